@@ -0,0 +1,124 @@
+use uefi::prelude::{Boot, SystemTable};
+use uefi::table::cfg::{ACPI2_GUID, ACPI_GUID};
+
+use no_std_compat::ptr;
+use no_std_compat::slice;
+
+use crate::ui::core::rotation::Rotation;
+
+/// Every ACPI table starts with a header this long
+const SDT_HEADER_SIZE: usize = 36;
+
+/// BGRT status: the logo is on screen right now
+const BGRT_DISPLAYED: u8 = 1;
+
+/// The firmware's boot logo, as described by the ACPI BGRT ("Boot Graphics
+/// Resource Table")
+pub struct BootLogo {
+    /// The logo as a BMP file
+    pub image: &'static [u8],
+    /// Where the firmware drew it, in px from the top-left of the screen
+    pub offset: (usize, usize),
+    /// How far the firmware turned it clockwise before drawing it
+    pub orientation: Rotation,
+}
+
+/// Read a `T` at physical address `addr`. Boot services memory is identity
+/// mapped so physical addresses work as pointers.
+unsafe fn read<T: Copy>(addr: usize) -> T {
+    return ptr::read_unaligned(addr as *const T);
+}
+
+/// ACPI tables are valid if all of their bytes add up to 0
+unsafe fn checksum_ok(addr: usize, len: usize) -> bool {
+    let bytes = slice::from_raw_parts(addr as *const u8, len);
+    return bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b)) == 0;
+}
+
+/// Address of the ACPI table with `signature`, found by way of the RSDP the
+/// firmware lists in its configuration tables. Prefers the XSDT (64-bit
+/// pointers) and falls back to the RSDT on ACPI 1.0 firmware.
+fn find_table(table: &SystemTable<Boot>, signature: &[u8; 4]) -> Option<usize> {
+    let rsdp = table
+        .config_table()
+        .iter()
+        .find(|entry| entry.guid == ACPI2_GUID)
+        .or_else(|| {
+            table
+                .config_table()
+                .iter()
+                .find(|entry| entry.guid == ACPI_GUID)
+        })?
+        .address as usize;
+
+    unsafe {
+        if read::<[u8; 8]>(rsdp) != *b"RSD PTR " {
+            return None;
+        }
+        let revision: u8 = read(rsdp + 15);
+        let xsdt: u64 = if revision >= 2 { read(rsdp + 24) } else { 0 };
+        let (sdt, entry_size) = if xsdt != 0 {
+            (xsdt as usize, 8)
+        } else {
+            (read::<u32>(rsdp + 16) as usize, 4)
+        };
+        if sdt == 0 {
+            return None;
+        }
+
+        let len = read::<u32>(sdt + 4) as usize;
+        let count = len.saturating_sub(SDT_HEADER_SIZE) / entry_size;
+        for i in 0..count {
+            let at = sdt + SDT_HEADER_SIZE + (i * entry_size);
+            let addr = if entry_size == 8 {
+                read::<u64>(at) as usize
+            } else {
+                read::<u32>(at) as usize
+            };
+            if addr != 0 && read::<[u8; 4]>(addr) == *signature {
+                return Some(addr);
+            }
+        }
+    }
+    return None;
+}
+
+/// The logo the firmware showed while booting, if it's still on screen. Only
+/// BMP logos are reported since that's the only kind the spec has.
+pub fn boot_logo(table: &SystemTable<Boot>) -> Option<BootLogo> {
+    let bgrt = find_table(table, b"BGRT")?;
+    unsafe {
+        let len = read::<u32>(bgrt + 4) as usize;
+        if len < SDT_HEADER_SIZE + 20 || !checksum_ok(bgrt, len) {
+            return None;
+        }
+        // if something has drawn over it since, showing it again would just
+        // flash it back up
+        let status: u8 = read(bgrt + 38);
+        if status & BGRT_DISPLAYED == 0 {
+            return None;
+        }
+        let orientation = match (status >> 1) & 0x3 {
+            0 => Rotation::Deg0,
+            1 => Rotation::Deg90,
+            2 => Rotation::Deg180,
+            _ => Rotation::Deg270,
+        };
+        // 0 is the only image type: BMP
+        let image_type: u8 = read(bgrt + 39);
+        let image = read::<u64>(bgrt + 40) as usize;
+        if image_type != 0 || image == 0 || read::<[u8; 2]>(image) != *b"BM" {
+            return None;
+        }
+        // the BMP's file header has its size
+        let size = read::<u32>(image + 2) as usize;
+        return Some(BootLogo {
+            image: slice::from_raw_parts(image as *const u8, size),
+            offset: (
+                read::<u32>(bgrt + 48) as usize,
+                read::<u32>(bgrt + 52) as usize,
+            ),
+            orientation: orientation,
+        });
+    }
+}
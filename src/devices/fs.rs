@@ -0,0 +1,91 @@
+use uefi::prelude::{Boot, SystemTable};
+use uefi::proto::media::file::{Directory, File, FileAttribute, FileMode, FileType, RegularFile};
+use uefi::{Handle, ResultExt};
+
+use no_std_compat::prelude::v1::{format, vec};
+use no_std_compat::string::String;
+use no_std_compat::vec::Vec;
+
+/// Root directory of the volume `image` was loaded from (the ESP, usually)
+fn open_boot_volume(table: &SystemTable<Boot>, image: Handle) -> Option<Directory> {
+    let fs = match table.boot_services().get_image_file_system(image) {
+        Ok(value) => value.log(),
+        Err(_) => return None,
+    };
+    let fs = unsafe { &mut *fs.get() };
+    return fs.open_volume().log_warning().ok();
+}
+
+/// Open `path` (relative to the root of the boot volume, `\` between
+/// directories) as a regular file.
+fn open_file(
+    table: &SystemTable<Boot>,
+    image: Handle,
+    path: &str,
+    mode: FileMode,
+) -> Option<RegularFile> {
+    let mut root = open_boot_volume(table, image)?;
+    let handle = root
+        .open(path, mode, FileAttribute::empty())
+        .log_warning()
+        .ok()?;
+    return match handle.into_type().log_warning() {
+        Ok(FileType::Regular(file)) => Some(file),
+        _ => None,
+    };
+}
+
+/// Everything in `path` on the boot volume, or `None` if it can't be read
+pub fn read_file(table: &SystemTable<Boot>, image: Handle, path: &str) -> Option<Vec<u8>> {
+    let mut file = open_file(table, image, path, FileMode::Read)?;
+    let mut data: Vec<u8> = Vec::new();
+    let mut chunk = vec![0u8; 4096];
+    loop {
+        let read = match file.read(&mut chunk) {
+            Ok(value) => value.log(),
+            Err(_) => return None,
+        };
+        if read == 0 {
+            return Some(data);
+        }
+        data.extend_from_slice(&chunk[..read]);
+    }
+}
+
+/// Write `data` to `path` on the boot volume, replacing anything already
+/// there. Returns false if anything goes wrong along the way.
+pub fn write_file(table: &SystemTable<Boot>, image: Handle, path: &str, data: &[u8]) -> bool {
+    // opening doesn't truncate, so get rid of the old one first
+    if let Some(old) = open_file(table, image, path, FileMode::ReadWrite) {
+        if old.delete().log_warning().is_err() {
+            return false;
+        }
+    }
+    let mut file = match open_file(table, image, path, FileMode::CreateReadWrite) {
+        Some(value) => value,
+        None => return false,
+    };
+    if file.write(data).log_warning().is_err() {
+        return false;
+    }
+    return file.flush().log_warning().is_ok();
+}
+
+/// `prefix-YYYYMMDD-HHMMSS.extension` using the firmware's clock. Good
+/// enough to keep files from stepping on each other.
+pub fn timestamped_name(table: &SystemTable<Boot>, prefix: &str, extension: &str) -> String {
+    return match table.runtime_services().get_time().log_warning() {
+        Ok(time) => format!(
+            "{}-{:04}{:02}{:02}-{:02}{:02}{:02}.{}",
+            prefix,
+            time.year(),
+            time.month(),
+            time.day(),
+            time.hour(),
+            time.minute(),
+            time.second(),
+            extension
+        ),
+        Err(_) => format!("{}.{}", prefix, extension),
+    };
+}
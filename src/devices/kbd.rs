@@ -19,6 +19,14 @@ pub fn read_char_raw(table: &mut SystemTable<Boot>) -> Key {
         .expect("no key was in the input buffer");
 }
 
+/// The next keypress if there's one waiting, without blocking
+pub fn poll_key(table: &mut SystemTable<Boot>) -> Option<Key> {
+    return match table.stdin().read_key() {
+        Ok(value) => value.log(),
+        Err(_) => None,
+    };
+}
+
 /// Unused, but returns keypresses if they're printable else None
 #[allow(dead_code)]
 pub fn read_char_printable(table: &mut SystemTable<Boot>) -> Option<char> {
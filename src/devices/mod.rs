@@ -1 +1,3 @@
+pub mod acpi;
+pub mod fs;
 pub mod kbd;
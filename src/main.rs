@@ -1,7 +1,10 @@
-#![no_main]
-#![no_std]
+#![cfg_attr(not(test), no_main)]
+#![cfg_attr(not(test), no_std)]
 #![feature(asm)]
 #![feature(abi_efiapi)]
+#![cfg_attr(test, feature(test))]
+// tests build for the host and only poke at the parts that don't need firmware
+#![cfg_attr(test, allow(dead_code, unused_imports))]
 
 use uefi::prelude::*;
 
@@ -14,47 +17,106 @@ use no_std_compat::string::*;
 mod devices;
 mod ui;
 
+#[cfg(test)]
+extern crate test;
+
 use crate::ui::components;
-use crate::ui::core::{application, bmp, graphics, widget};
+use crate::ui::core::{application, bitmap, display, font, graphics, resolution, scale, widget};
 
+#[cfg(not(test))]
 #[entry]
-fn efi_main(_image: Handle, mut table: SystemTable<Boot>) -> Status {
+fn efi_main(image: Handle, mut table: SystemTable<Boot>) -> Status {
     uefi_services::init(&mut table).expect_success("failed to init");
 
     info!("setting up app");
 
     // font size 1 is tiny so i recommend 2 by default
     let font_sizes = graphics::FontSizes::new(3 /* h1 */, 2 /* h2 */, 2 /* p */);
+    // colors are [r, g, b]
     let color_scheme = graphics::ColorScheme::new(
-        [0x8c, 0x79, 0x40], /* Foreground */
+        [0x40, 0x79, 0x8c], /* Foreground */
         [0x0f, 0x0f, 0x0f], /* Background */
-        [0x80, 0x77, 0x38], /* Cursor */
-        [0xf7, 0xff, 0xdd], /* BorderUnfocused */
-        [0xd8, 0xe1, 0x93], /* BorderFocused */
+        [0x38, 0x77, 0x80], /* Cursor */
+        [0xdd, 0xff, 0xf7], /* BorderUnfocused */
+        [0x93, 0xe1, 0xd8], /* BorderFocused */
     );
     let theme = graphics::Theme {
         font_sizes: font_sizes,
         color_scheme: color_scheme,
+        font: String::from(font::BUILTIN),
     };
 
+    // 1024x600 if it's there, otherwise whatever's nearest
+    let mut application = application::Application::new(
+        image,
+        table,
+        theme,
+        display::DisplayOptions::default(),
+        resolution::ResolutionPolicy::Closest((1024, 600)),
+    );
+    // some panels only do their native mode, so scale text up to stay readable.
+    // sizes don't have to be whole, 4x is a bit much for body text
+    if application.resolution().0 >= 1920 {
+        let text = scale::Scale::ratio(7, 2);
+        let font_sizes =
+            graphics::FontSizes::new(5 /* h1 */, 4 /* h2 */, text /* p */);
+        application.set_font_sizes(font_sizes);
+    }
+
+    // a `font.psf` console font or `font.bdf` X11 font in the root of the ESP
+    // replaces the built-in one
+    let loaders: [(&str, fn(&[u8]) -> Result<font::Font, &'static str>); 2] = [
+        ("font.psf", font::psf2::parse),
+        ("font.bdf", font::bdf::parse),
+    ];
+    for (path, parse) in loaders.iter() {
+        let data = match application.read_file(path) {
+            Some(value) => value,
+            None => continue,
+        };
+        match parse(&data) {
+            Ok(font) => {
+                application.add_font(path, font);
+                application.set_font(path);
+                break;
+            }
+            Err(e) => info!("couldn't load {}: {}", path, e),
+        }
+    }
+
+    // pick up where the firmware's boot logo left off, if it had one
+    application.splash(1000 /* hold_ms */);
+
     let text_input = components::text_input::TextInput::new(
         String::from("textinput"),
         (0, 0),
-        (1024, 600),
+        application.resolution(),
         widget::XOverflowBehavior::Wrap,
     );
+    application.push(Box::from(text_input));
 
-    let mut application = application::Application::new(table, theme, Box::from(text_input));
+    // an `image.qoi` or `image.bmp` in the root of the ESP gets shown on top.
+    // escape closes it
+    for path in ["image.qoi", "image.bmp"].iter() {
+        let data = match application.read_file(path) {
+            Some(value) => value,
+            None => continue,
+        };
+        let resolution = application.resolution();
+        let mode = widget::ImageMode::Fit;
+        match widget::Image::from_bytes(String::from("image"), &data, mode, (0, 0), resolution) {
+            Ok(image) => {
+                application.push(Box::from(image));
+                break;
+            }
+            Err(e) => info!("couldn't show {}: {}", path, e),
+        }
+    }
     application.run_loop();
 
     // i keep my todo lists in my code, sue me
-    // - tint non-focused/background components
-    // - handle "un-drawing" closed widgets
-    //   - fake an alpha channel?
-    //   - just redraw up the whole UI stack
     // - improve performance
     //   - drawing backgrounds seems to slow the whole thing waaaaay down
-    //   - memoize bitmaps for characters as they're used to limit allocations
     // - UIResult::Open(new_widget);
     // - implement resizing and tiling in MultiWidget
     // - UEFI watchdog timer
@@ -2,12 +2,14 @@ use log::info;
 
 use uefi::proto::console::text::{Key, ScanCode};
 
+use no_std_compat::cmp;
 use no_std_compat::prelude::v1::{vec, Box};
 use no_std_compat::string::String;
 use no_std_compat::vec::Vec;
 
 use crate::graphics::{FontSize, Graphics};
-use crate::ui::core::{font, UIResult};
+use crate::ui::core::rect::Rect;
+use crate::ui::core::UIResult;
 use crate::widget::{Button, MultiWidget, Widget};
 
 const BUTTON_PADDING: u8 = 3;
@@ -27,6 +29,7 @@ pub enum MenuOrientation {
 pub struct Menu {
     id: String,
     orientation: MenuOrientation,
+    choices: Vec<String>,
 
     // computed
     multiwidget: MultiWidget,
@@ -41,86 +44,106 @@ impl Menu {
         dimensions_px: (usize, usize),
         orientation: MenuOrientation,
     ) -> Menu {
-        let mut longest_choice: usize = 0;
-        for choice in &choices {
-            if choice.len() > longest_choice {
-                longest_choice = choice.len();
-            }
+        // no `Graphics` to measure the labels with yet. `resize()` lays the
+        // buttons out for real, and `Application::push()` calls it
+        let bounds = Rect::new(start_px, dimensions_px);
+        let button_rects = Menu::layout(&orientation, choices.len(), (0, 0), bounds);
+
+        let mut subscriptions: Vec<String> = vec![id.clone()];
+        let mut buttons: Vec<Box<dyn Widget>> = Vec::new();
+        for (choice, rect) in choices.iter().zip(button_rects) {
+            let choice_id = id.clone() + choice + "_button";
+            subscriptions.push(choice_id.clone());
+            let button = Button::new(
+                choice_id,
+                choice.clone(),
+                rect.top_left(),
+                rect.dimensions(),
+                FontSize::P,
+            );
+            buttons.push(Box::from(button));
         }
 
-        let char_width = 2 * font::FONT_WIDTH;
-        let char_height = 2 * font::FONT_HEIGHT;
+        let multiwidget_id = id.clone() + "_multiwidget";
+        let multiwidget = MultiWidget::new(
+            multiwidget_id.clone(),
+            buttons,
+            0,             /* focused */
+            start_px,      /* start */
+            dimensions_px, /* dimensions */
+        );
+
+        Menu {
+            id: id.clone(),
+            orientation: orientation,
+            choices: choices,
+            multiwidget: multiwidget,
+            subscriptions: subscriptions,
+        }
+    }
 
-        let button_width = longest_choice * char_width + usize::from(BUTTON_PADDING);
-        let button_height = char_height + usize::from(BUTTON_PADDING);
+    /// Where each of `count` buttons goes inside `bounds`. Buttons are all
+    /// sized to fit the widest label, `label_dims` (x, y) px, as long as they
+    /// still fit in `bounds`, and spread out evenly.
+    fn layout(
+        orientation: &MenuOrientation,
+        count: usize,
+        label_dims: (usize, usize),
+        bounds: Rect,
+    ) -> Vec<Rect> {
+        let (start_px, dimensions_px) = (bounds.top_left(), bounds.dimensions());
+        // labels that don't fit get cut short by the buttons themselves
+        let max_width = if *orientation == MenuOrientation::VERTICAL {
+            dimensions_px.0
+        } else {
+            dimensions_px.0 / cmp::max(count, 1)
+        };
+        let button_width = cmp::min(label_dims.0 + usize::from(BUTTON_PADDING), max_width);
+        let button_height = label_dims.1 + usize::from(BUTTON_PADDING);
         let button_dimensions = (button_width, button_height);
 
         let mut button_start: (usize, usize);
         let button_x_step: usize;
         let button_y_step: usize;
-        if orientation == MenuOrientation::VERTICAL {
+        if *orientation == MenuOrientation::VERTICAL {
             button_x_step = 0;
-            let button_x_start = start_px.0 + ((dimensions_px.0 - button_width) / 2);
+            let button_x_start = start_px.0 + (dimensions_px.0.saturating_sub(button_width) / 2);
 
-            let button_area_height = button_height * choices.len();
+            let button_area_height = button_height * count;
             if button_area_height >= dimensions_px.1 {
                 // rely on scrolling and just stack buttons up
                 // need to refactor to support scrolling but may as well leave this here
                 button_start = (button_x_start, start_px.1);
                 button_y_step = button_height;
             } else {
-                let separation = (dimensions_px.1 - button_area_height) / (choices.len() + 1);
+                let separation = (dimensions_px.1 - button_area_height) / (count + 1);
                 button_start = (button_x_start, start_px.1 + separation);
                 button_y_step = button_height + separation;
             }
         } else {
             button_y_step = 0;
-            let button_y_start = start_px.1 + ((dimensions_px.1 - button_height) / 2);
+            let button_y_start = start_px.1 + (dimensions_px.1.saturating_sub(button_height) / 2);
 
-            let button_area_width = button_width * choices.len();
+            let button_area_width = button_width * count;
             if button_area_width >= dimensions_px.0 {
                 // rely on scrolling and just sit buttons next to each other
                 // need to refactor to support scrolling but may as well leave this here
                 button_start = (start_px.0, button_y_start);
                 button_x_step = button_width;
             } else {
-                let separation = (dimensions_px.0 - button_area_width) / (choices.len() + 1);
+                let separation = (dimensions_px.0 - button_area_width) / (count + 1);
                 button_start = (start_px.0 + separation, button_y_start);
                 button_x_step = button_width + separation;
             }
         }
 
-        let mut subscriptions: Vec<String> = vec![id.clone()];
-        let mut buttons: Vec<Box<dyn Widget>> = Vec::new();
-        for choice in &choices {
-            let choice_id = id.clone() + choice + "_button";
-            subscriptions.push(choice_id.clone());
-            let button = Button::new(
-                choice_id,
-                choice.clone(),
-                button_start,
-                button_dimensions,
-                FontSize::P,
-            );
-            buttons.push(Box::from(button));
+        let mut rects = Vec::new();
+        for _ in 0..count {
+            rects.push(Rect::new(button_start, button_dimensions));
             button_start.0 += button_x_step;
             button_start.1 += button_y_step;
         }
-
-        let multiwidget_id = id.clone() + "_multiwidget";
-        let multiwidget = MultiWidget::new(
-            multiwidget_id.clone(),
-            buttons,
-            0,             /* focused */
-            dimensions_px, /* dimensions */
-        );
-
-        Menu {
-            id: id.clone(),
-            orientation: orientation,
-            multiwidget: multiwidget,
-            subscriptions: subscriptions,
-        }
+        return rects;
     }
 }
 
@@ -145,6 +168,10 @@ impl Widget for Menu {
         self.multiwidget.draw(graphics, focused);
     }
 
+    fn invalidate(&mut self, graphics: &mut Graphics, rect: Rect, focused: bool) {
+        self.multiwidget.invalidate(graphics, rect, focused);
+    }
+
     fn handle_key(&mut self, k: Key, graphics: &mut Graphics) -> UIResult {
         let mut result = UIResult::OK;
         match k {
@@ -190,4 +217,83 @@ impl Widget for Menu {
     fn dimensions(&mut self) -> (usize, usize) {
         return self.multiwidget.dimensions();
     }
+
+    fn bounds(&self) -> Rect {
+        return self.multiwidget.bounds();
+    }
+
+    /// Buttons get resized to fit their labels in the current font and spread
+    /// out again.
+    fn resize(&mut self, graphics: &Graphics, rect: Rect) {
+        let label_dims = self
+            .choices
+            .iter()
+            .map(|choice| graphics.measure_text(choice, FontSize::P))
+            .fold((0, 0), |dims, (x, y)| {
+                (cmp::max(dims.0, x), cmp::max(dims.1, y))
+            });
+        let button_rects = Menu::layout(&self.orientation, self.choices.len(), label_dims, rect);
+        self.multiwidget.resize(graphics, rect);
+        for (i, button_rect) in button_rects.into_iter().enumerate() {
+            self.multiwidget.resize_component(graphics, i, button_rect);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PADDING: usize = BUTTON_PADDING as usize;
+
+    #[test]
+    fn vertical_buttons_are_spread_out() {
+        let bounds = Rect::new((0, 10), (100, 100));
+        let rects = Menu::layout(&MenuOrientation::VERTICAL, 2, (20, 10), bounds);
+        let size = (20 + PADDING, 10 + PADDING);
+        // centered across, and the gaps above, between and below are the same
+        let x = (100 - size.0) / 2;
+        let gap = (100 - 2 * size.1) / 3;
+        assert_eq!(
+            rects,
+            [
+                Rect::new((x, 10 + gap), size),
+                Rect::new((x, 10 + gap + size.1 + gap), size),
+            ]
+        );
+    }
+
+    #[test]
+    fn horizontal_buttons_are_spread_out() {
+        let bounds = Rect::new((10, 0), (100, 30));
+        let rects = Menu::layout(&MenuOrientation::HORIZONTAL, 3, (10, 10), bounds);
+        let size = (10 + PADDING, 10 + PADDING);
+        let y = (30 - size.1) / 2;
+        let gap = (100 - 3 * size.0) / 4;
+        for (i, rect) in rects.iter().enumerate() {
+            assert_eq!(*rect, Rect::new((10 + gap + i * (size.0 + gap), y), size));
+        }
+    }
+
+    #[test]
+    fn buttons_that_dont_fit_stack_up() {
+        let bounds = Rect::new((0, 0), (10, 20));
+        let rects = Menu::layout(&MenuOrientation::VERTICAL, 3, (20, 10), bounds);
+        // no wider than the menu either
+        let size = (10, 10 + PADDING);
+        for (i, rect) in rects.iter().enumerate() {
+            assert_eq!(*rect, Rect::new((0, i * size.1), size));
+        }
+    }
+
+    #[test]
+    fn wide_labels_share_the_width() {
+        let bounds = Rect::new((0, 0), (90, 30));
+        let rects = Menu::layout(&MenuOrientation::HORIZONTAL, 3, (50, 10), bounds);
+        let size = (30, 10 + PADDING);
+        let y = (30 - size.1) / 2;
+        for (i, rect) in rects.iter().enumerate() {
+            assert_eq!(*rect, Rect::new((i * 30, y), size));
+        }
+    }
 }
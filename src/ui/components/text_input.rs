@@ -2,14 +2,19 @@ use log::info;
 
 use uefi::proto::console::text::{Key, ScanCode};
 
+use no_std_compat::cmp;
 use no_std_compat::prelude::v1::{vec, Box};
 use no_std_compat::string::String;
 use no_std_compat::vec::Vec;
 
 use crate::graphics::{FontSize, Graphics};
 use crate::ui::components::menu::{Menu, MenuOrientation};
+use crate::ui::core::rect::Rect;
 use crate::ui::core::UIResult;
-use crate::widget::{MultiWidget, TextArea, Widget, XOverflowBehavior};
+use crate::widget::{MultiWidget, TextArea, TextAreaOptions, Widget, XOverflowBehavior};
+
+/// Height of the save/cancel `Menu` along the bottom, in px
+const MENU_HEIGHT: usize = 30;
 
 /// `Widget` combining a text input area (`TextArea`) with a set of buttons.
 /// "Cancel" button just exits and "Save" button posts the data. Posting
@@ -35,24 +40,29 @@ impl TextInput {
         dimensions_px: (usize, usize),
         x_overflow: XOverflowBehavior,
     ) -> TextInput {
+        let (text_area_rect, menu_rect) = TextInput::layout(Rect::new(start_px, dimensions_px));
+
         let text_area_id = id.clone() + "_textarea";
         let text_area = TextArea::new(
-            text_area_id.clone(),                    /* id */
-            Vec::new(),                              /* subscriptions */
-            String::new(),                           /* content */
-            true,                                    /* edit */
-            start_px,                                /* start */
-            (dimensions_px.0, dimensions_px.1 - 30), /* dimensions_px */
-            FontSize::P,                             /* font_size */
-            x_overflow,
+            text_area_id.clone(),        /* id */
+            Vec::new(),                  /* subscriptions */
+            String::new(),               /* content */
+            true,                        /* edit */
+            text_area_rect.top_left(),   /* start */
+            text_area_rect.dimensions(), /* dimensions_px */
+            TextAreaOptions {
+                font_size: FontSize::P,
+                x_overflow: x_overflow,
+                monospace: false,
+            },
         );
 
         let menu_id = id.clone() + "_action_menu";
         let menu = Menu::new(
             menu_id.clone(),
             vec![String::from("save"), String::from("cancel")],
-            (start_px.0, start_px.1 + dimensions_px.1 - 30), // start_px
-            (dimensions_px.0, 30),                           // dimensions_px
+            menu_rect.top_left(),   // start_px
+            menu_rect.dimensions(), // dimensions_px
             MenuOrientation::HORIZONTAL,
         );
 
@@ -61,6 +71,7 @@ impl TextInput {
             multiwidget_id.clone(),
             vec![Box::from(text_area), Box::from(menu)],
             0,             /* focused */
+            start_px,      /* start */
             dimensions_px, /* dimensions */
         );
 
@@ -70,6 +81,19 @@ impl TextInput {
             subscriptions: vec![menu_id],
         }
     }
+
+    /// Split `bounds` into the `TextArea` on top and the `Menu` underneath.
+    fn layout(bounds: Rect) -> (Rect, Rect) {
+        let menu_height = cmp::min(MENU_HEIGHT, bounds.height);
+        let text_area_height = bounds.height - menu_height;
+        return (
+            Rect::new(bounds.top_left(), (bounds.width, text_area_height)),
+            Rect::new(
+                (bounds.x, bounds.y + text_area_height),
+                (bounds.width, menu_height),
+            ),
+        );
+    }
 }
 
 impl Widget for TextInput {
@@ -94,6 +118,10 @@ impl Widget for TextInput {
         self.multiwidget.draw(graphics, focused);
     }
 
+    fn invalidate(&mut self, graphics: &mut Graphics, rect: Rect, focused: bool) {
+        self.multiwidget.invalidate(graphics, rect, focused);
+    }
+
     fn handle_key(&mut self, k: Key, graphics: &mut Graphics) -> UIResult {
         if let Key::Special(value) = k {
             if ScanCode::ESCAPE == value {
@@ -114,4 +142,35 @@ impl Widget for TextInput {
     fn dimensions(&mut self) -> (usize, usize) {
         return self.multiwidget.dimensions();
     }
+
+    fn bounds(&self) -> Rect {
+        return self.multiwidget.bounds();
+    }
+
+    fn resize(&mut self, graphics: &Graphics, rect: Rect) {
+        let (text_area_rect, menu_rect) = TextInput::layout(rect);
+        self.multiwidget.resize(graphics, rect);
+        self.multiwidget
+            .resize_component(graphics, 0, text_area_rect);
+        self.multiwidget.resize_component(graphics, 1, menu_rect);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn menu_goes_along_the_bottom() {
+        let (text_area, menu) = TextInput::layout(Rect::new((10, 20), (100, 200)));
+        assert_eq!(text_area, Rect::new((10, 20), (100, 200 - MENU_HEIGHT)));
+        assert_eq!(menu, Rect::new((10, 220 - MENU_HEIGHT), (100, MENU_HEIGHT)));
+    }
+
+    #[test]
+    fn menu_gets_whatever_fits() {
+        let (text_area, menu) = TextInput::layout(Rect::new((0, 0), (100, 20)));
+        assert_eq!(text_area, Rect::new((0, 0), (100, 0)));
+        assert_eq!(menu, Rect::new((0, 0), (100, 20)));
+    }
 }
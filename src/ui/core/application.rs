@@ -1,19 +1,41 @@
 use uefi::prelude::Boot;
 use uefi::prelude::SystemTable;
-use uefi::proto::console::gop::GraphicsOutput;
-use uefi::proto::console::text::Key;
-use uefi::ResultExt;
+use uefi::proto::console::text::{Key, ScanCode};
+use uefi::Handle;
 
-use no_std_compat::prelude::v1::{vec, Box};
+use no_std_compat::cmp;
+use no_std_compat::prelude::v1::Box;
+use no_std_compat::string::String;
 use no_std_compat::vec::Vec;
 
-use crate::devices::kbd;
-use crate::graphics::{Graphics, Theme};
+use crate::devices::{acpi, fs, kbd};
+use crate::graphics::{FontSizes, Graphics, Theme};
+use crate::ui::core::codec;
+use crate::ui::core::display::{self, DisplayOptions};
+use crate::ui::core::font::Font;
+use crate::ui::core::rect::Rect;
+use crate::ui::core::resolution::ResolutionPolicy;
+use crate::ui::core::rotation::Rotation;
 use crate::ui::core::UIResult;
 use crate::widget::Widget;
 
 type UIStack = Vec<Box<dyn Widget>>;
 
+/// How much of the background color gets blended over each layer that isn't
+/// at the top of the `UIStack` (out of 255). Dimming stacks up, so the deeper a
+/// layer is the darker it gets.
+const INACTIVE_LAYER_TINT: u8 = 128;
+
+/// Turns the picture a quarter turn clockwise no matter what has focus
+const ROTATE_KEY: ScanCode = ScanCode::FUNCTION_11;
+
+/// Saves a screenshot to the boot volume no matter what has focus
+const SCREENSHOT_KEY: ScanCode = ScanCode::FUNCTION_12;
+
+/// How long the splash takes to fade away, and in how many steps
+const SPLASH_FADE_MS: usize = 400;
+const SPLASH_FADE_STEPS: usize = 16;
+
 /// `Application` is the top-level component. It takes ownership of the UEFI
 /// `SystemTable`.
 ///
@@ -21,54 +43,242 @@ type UIStack = Vec<Box<dyn Widget>>;
 /// and forwards them to the top `Widget` on the UI stack. When it receives a
 /// `UIResult::POST()` it will run up the stack and forward it to any `Widget`
 /// that subscribes to the id it was posted with.
+///
+/// Every `Widget` on the UI stack draws into its own `Graphics` layer, so when
+/// one closes whatever it was covering shows up again on its own. Use `push()`
+/// rather than pushing onto `ui_stack` directly to keep the two in step.
+///
+/// The UI stack starts out empty so the first `Widget` can be sized to fit
+/// `resolution()`; push it before calling `run_loop()`.
+///
+/// F11 turns the picture a quarter turn clockwise, for panels that are mounted
+/// sideways. F12 saves a screenshot to the volume the app was loaded from.
 pub struct Application<'a> {
+    image: Handle,
     table: SystemTable<Boot>,
     graphics: Graphics<'a>,
     pub ui_stack: UIStack,
+
+    // the firmware's resolution before we changed it, which is what the boot
+    // logo was positioned for
+    firmware_resolution: (usize, usize),
 }
 
 impl<'_static> Application<'static> {
     /// Create an `Application`.
     ///
+    /// `image`: handle of the running image, which is how we find the volume
+    /// we were loaded from
     /// `table`: the UEFI `SystemTable`, moved into `Application`
     /// `theme`: a theme defining some colors and font scaling factors
-    /// `initial_ui` the `Widget` at the bottom of the UI stack. The homepage as it were
+    /// `display`: which display to draw on if there are several, and whether
+    /// to mirror to the rest
+    /// `resolution`: how to pick the screen resolution out of whatever modes
+    /// the firmware has. It's set right away.
     pub fn new(
+        image: Handle,
         table: SystemTable<Boot>,
         theme: Theme,
-        initial_ui: Box<dyn Widget>,
+        display: DisplayOptions,
+        resolution: ResolutionPolicy,
     ) -> Application<'_static> {
-        let gop = table
-            .boot_services()
-            .locate_protocol::<GraphicsOutput>()
-            .expect_success("failed to load graphics protocol");
-        let gop = unsafe { &mut *gop.get() };
-
         // ALERT: technically Graphics still has a mutable ref to part of SystemTable
         // which means we're being naughty when we later take a mutable borrow of
         // SystemTable to await keystrokes
-        let graphics = Graphics::new(gop, theme);
+        let target = display::open(table.boot_services(), &display);
+        let graphics = Graphics::new(target, theme);
+        let firmware_resolution = graphics.resolution();
 
-        Application {
+        let mut application = Application {
+            image: image,
             table: table,
             graphics: graphics,
-            ui_stack: vec![initial_ui],
+            ui_stack: Vec::new(),
+            firmware_resolution: firmware_resolution,
+        };
+        application.set_resolution(resolution);
+        return application;
+    }
+
+    /// (x, y) size of the screen in px
+    pub fn resolution(&self) -> (usize, usize) {
+        return self.graphics.resolution();
+    }
+
+    /// Switch to a different resolution picked by `policy`, lay every `Widget`
+    /// out again to fit and redraw everything.
+    pub fn set_resolution(&mut self, policy: ResolutionPolicy) {
+        let old_resolution = self.graphics.resolution();
+        self.graphics.negotiate_resolution(policy);
+        self.relayout(old_resolution);
+    }
+
+    /// Turn the picture for a panel that's mounted sideways or upside down.
+    /// Every `Widget` is laid out again against the turned screen.
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        let old_resolution = self.graphics.resolution();
+        self.graphics.set_rotation(rotation);
+        self.relayout(old_resolution);
+    }
+
+    /// Change the theme's font sizes and lay every `Widget` out again to fit.
+    pub fn set_font_sizes(&mut self, font_sizes: FontSizes) {
+        self.graphics.theme.font_sizes = font_sizes;
+        self.relayout(self.graphics.resolution());
+    }
+
+    /// Make `font` available as `name` for `set_font()`, e.g. one parsed from
+    /// `read_file()` with `font::psf2::parse()` or `font::bdf::parse()`.
+    pub fn add_font(&mut self, name: &str, font: Font) {
+        self.graphics.add_font(name, font);
+    }
+
+    /// Switch the theme to the font called `name` and lay every `Widget` out
+    /// again for its size.
+    pub fn set_font(&mut self, name: &str) {
+        self.graphics.theme.font = String::from(name);
+        self.relayout(self.graphics.resolution());
+    }
+
+    /// Resize every `Widget` on the stack and redraw.
+    fn relayout(&mut self, old_resolution: (usize, usize)) {
+        Application::resize_all(&mut self.ui_stack, &self.graphics, old_resolution);
+        if self.ui_stack.len() > 0 {
+            self.draw();
+        }
+    }
+
+    /// Resize every `Widget` in `ui_stack`, stretching its bounds along with
+    /// the screen if the resolution went from `old_resolution` to something
+    /// else.
+    fn resize_all(ui_stack: &mut UIStack, graphics: &Graphics, old_resolution: (usize, usize)) {
+        let resolution = graphics.resolution();
+        for widget in ui_stack.iter_mut() {
+            let rect = widget.bounds().scale(old_resolution, resolution);
+            widget.resize(graphics, rect);
         }
     }
 
-    /// Draw every component from the bottom of the stack to the top.
+    /// Draw every component from the bottom of the stack to the top, each into
+    /// its own layer. Every layer but the top one is dimmed.
     fn draw(&mut self) {
+        // one layer per `Widget` on the stack
+        while self.graphics.layer_count() < self.ui_stack.len() {
+            self.graphics.push_layer();
+        }
         for i in 0..self.ui_stack.len() - 1 {
+            self.graphics.set_active_layer(i);
+            self.graphics.set_layer_dim(i, INACTIVE_LAYER_TINT);
             self.ui_stack[i].draw(&mut self.graphics, false);
         }
+        self.draw_top();
+    }
+
+    /// Draw just the top of the stack. The layers underneath already have
+    /// everything they need.
+    fn draw_top(&mut self) {
+        let top = self.ui_stack.len() - 1;
+        self.graphics.set_active_layer(top);
+        self.graphics.set_layer_dim(top, 0);
         self.ui_stack
             .last_mut()
             .expect("UIStack should not be empty")
             .draw(&mut self.graphics, true);
     }
 
+    /// Open a new `Widget` on top of the stack. It gets focus and everything
+    /// else is dimmed. It's resized first so it can lay itself out for the
+    /// theme's font.
+    pub fn push(&mut self, mut widget: Box<dyn Widget>) {
+        // `Graphics` starts out with a layer for the first `Widget`
+        if self.ui_stack.len() > 0 {
+            let below = self.ui_stack.len() - 1;
+            self.graphics.set_layer_dim(below, INACTIVE_LAYER_TINT);
+            self.graphics.push_layer();
+        }
+        let bounds = widget.bounds();
+        widget.resize(&self.graphics, bounds);
+        self.ui_stack.push(widget);
+        self.draw_top();
+    }
+
+    /// Show the firmware's boot logo (from the ACPI BGRT table) on black, the
+    /// way the firmware left it, for `hold_ms` and then fade it out. A keypress
+    /// gets rid of it right away; the key isn't passed on. Call it before the
+    /// first `push()`. Returns false if there's no logo to show.
+    pub fn splash(&mut self, hold_ms: usize) -> bool {
+        assert!(
+            self.ui_stack.len() == 0,
+            "splash() goes before the first push()"
+        );
+        let logo = match acpi::boot_logo(&self.table) {
+            Some(value) => value,
+            None => return false,
+        };
+        let mut bitmap = match codec::bmp::decode(logo.image) {
+            Ok(value) => value.rotate(logo.orientation),
+            Err(e) => {
+                log::info!("couldn't decode the boot logo: {}", e);
+                return false;
+            }
+        };
+
+        // keep the logo's middle the same distance from the middle of the
+        // screen, in case the resolution changed since the firmware drew it
+        let place = |offset: usize, size: usize, old: usize, new: usize| -> usize {
+            let from_middle = (offset + size / 2) as isize - (old / 2) as isize;
+            return cmp::max((new / 2) as isize + from_middle - (size / 2) as isize, 0) as usize;
+        };
+        let (dims, old, new) = (
+            bitmap.dimensions(),
+            self.firmware_resolution,
+            self.graphics.resolution(),
+        );
+        let top_left = (
+            place(logo.offset.0, dims.0, old.0, new.0),
+            place(logo.offset.1, dims.1, old.1, new.1),
+        );
+
+        self.graphics.set_active_layer(0);
+        self.graphics.fill_rect(self.graphics.screen(), [0, 0, 0]);
+        self.graphics.draw_bitmap(&mut bitmap, top_left);
+        self.graphics.present();
+
+        let area = Rect::new(top_left, dims);
+        let mut skipped = false;
+        for _ in 0..(hold_ms / 10) {
+            if kbd::poll_key(&mut self.table).is_some() {
+                skipped = true;
+                break;
+            }
+            self.table.boot_services().stall(10_000);
+        }
+        if !skipped {
+            for step in 1..=SPLASH_FADE_STEPS {
+                if kbd::poll_key(&mut self.table).is_some() {
+                    break;
+                }
+                // redraw just the logo and darken it, so each step only
+                // sends that much to the screen
+                let dim = (step * 255) / SPLASH_FADE_STEPS;
+                self.graphics.fill_rect(area, [0, 0, 0]);
+                self.graphics.draw_bitmap(&mut bitmap, top_left);
+                self.graphics.tint(area, [0, 0, 0], dim as u8);
+                self.graphics.present();
+                self.table
+                    .boot_services()
+                    .stall((SPLASH_FADE_MS * 1000) / SPLASH_FADE_STEPS);
+            }
+        }
+
+        self.graphics.clear_layer();
+        self.graphics.present();
+        return true;
+    }
+
     /// Handle a keypress by giving it to the `Widget` at the top of the `UIStack`.
     fn handle_key(&mut self, k: Key) -> UIResult {
+        self.graphics.set_active_layer(self.ui_stack.len() - 1);
         return self
             .ui_stack
             .last_mut()
@@ -76,15 +286,43 @@ impl<'_static> Application<'static> {
             .handle_key(k, &mut self.graphics);
     }
 
-    /// Set the resolution here (for lack of a better place) and then loop:
+    /// Read a file from the volume the app was loaded from, e.g. an image for
+    /// `widget::Image::from_bytes()`. `path` uses `\` between directories.
+    pub fn read_file(&self, path: &str) -> Option<Vec<u8>> {
+        return fs::read_file(&self.table, self.image, path);
+    }
+
+    /// Save whatever's on screen as a timestamped BMP in the root of the boot
+    /// volume.
+    fn screenshot(&mut self) {
+        let bmp = self.graphics.capture();
+        let name = fs::timestamped_name(&self.table, "screenshot", "bmp");
+        if fs::write_file(&self.table, self.image, &name, &bmp) {
+            log::info!("saved {}", name);
+        } else {
+            log::info!("couldn't save {}", name);
+        }
+    }
+
     /// Listen for keystroke after keystroke, forward them to the top of the UI
-    /// stack, and handle the `UIResult` values they return.
+    /// stack, and handle the `UIResult` values they return. Whatever got drawn
+    /// while handling a keystroke is presented once it's been handled.
     pub fn run_loop(&mut self) {
-        self.graphics.set_resolution((1024, 600));
+        assert!(self.ui_stack.len() > 0, "push() a Widget before run_loop()");
         self.draw();
+        self.graphics.present();
 
         loop {
             let c = kbd::read_char_raw(&mut self.table);
+            if c == Key::Special(ROTATE_KEY) {
+                self.set_rotation(self.graphics.rotation().clockwise());
+                self.graphics.present();
+                continue;
+            }
+            if c == Key::Special(SCREENSHOT_KEY) {
+                self.screenshot();
+                continue;
+            }
             let result = self.handle_key(c);
 
             match result {
@@ -94,7 +332,10 @@ impl<'_static> Application<'static> {
                     if self.ui_stack.len() == 0 {
                         return;
                     }
-                    self.draw();
+                    // the closed widget's layer goes with it. the new top gets
+                    // redrawn because it's focused now; nothing else has to
+                    self.graphics.pop_layer();
+                    self.draw_top();
                 }
                 UIResult::POST(id, data) => {
                     for widget in &mut self.ui_stack {
@@ -105,6 +346,73 @@ impl<'_static> Application<'static> {
                     }
                 }
             }
+            self.graphics.present();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::{ColorScheme, FontSize};
+    use crate::ui::core::font;
+    use crate::ui::core::rect::Rect;
+    use crate::ui::core::render_target::MemoryTarget;
+    use crate::widget::{TextArea, TextAreaOptions};
+    use no_std_compat::string::String;
+
+    fn graphics(resolution: (usize, usize)) -> Graphics<'static> {
+        let theme = Theme {
+            font_sizes: FontSizes::new(1, 1, 1),
+            color_scheme: ColorScheme::new(
+                [255, 255, 255],
+                [0, 0, 0],
+                [0, 255, 0],
+                [90, 90, 90],
+                [0, 0, 255],
+            ),
+            font: String::from(font::BUILTIN),
+        };
+        return Graphics::new(Box::new(MemoryTarget::new(resolution)), theme);
+    }
+
+    fn text_area(start: (usize, usize), dimensions: (usize, usize)) -> Box<dyn Widget> {
+        return Box::new(TextArea::new(
+            String::from("text"),
+            Vec::new(),
+            String::new(),
+            true,
+            start,
+            dimensions,
+            TextAreaOptions::default(),
+        ));
+    }
+
+    #[test]
+    fn widgets_stretch_with_the_screen() {
+        let mut graphics = graphics((100, 100));
+        let mut ui_stack: UIStack =
+            vec![text_area((0, 0), (100, 100)), text_area((50, 10), (50, 20))];
+        graphics.set_resolution((200, 50));
+        Application::resize_all(&mut ui_stack, &graphics, (100, 100));
+        assert_eq!(ui_stack[0].bounds(), Rect::new((0, 0), (200, 50)));
+        assert_eq!(ui_stack[1].bounds(), Rect::new((100, 5), (100, 10)));
+    }
+
+    #[test]
+    fn same_resolution_stays_put() {
+        let graphics = graphics((100, 100));
+        let mut ui_stack: UIStack = vec![text_area((50, 10), (50, 20))];
+        Application::resize_all(&mut ui_stack, &graphics, (100, 100));
+        assert_eq!(ui_stack[0].bounds(), Rect::new((50, 10), (50, 20)));
+    }
+
+    #[test]
+    fn widgets_follow_the_screen_around() {
+        let mut graphics = graphics((100, 50));
+        let mut ui_stack: UIStack = vec![text_area((0, 0), (100, 50))];
+        graphics.set_rotation(Rotation::Deg90);
+        Application::resize_all(&mut ui_stack, &graphics, (100, 50));
+        assert_eq!(ui_stack[0].bounds(), Rect::new((0, 0), (50, 100)));
+    }
+}
@@ -0,0 +1,680 @@
+use no_std_compat::cmp;
+use no_std_compat::prelude::v1::vec;
+use no_std_compat::prelude::v1::Box;
+use no_std_compat::vec::Vec;
+
+use crate::graphics::{blend, Color};
+use crate::ui::core::rect::Rect;
+use crate::ui::core::render_target::{PixelEncoder, RenderTarget};
+use crate::ui::core::rotation::Rotation;
+use crate::ui::core::scale::Scale;
+
+/// if true, all bitmaps have a white border
+pub static mut DEBUG_BORDER: bool = false;
+
+/// How to fill in the pixels of a resized `Bitmap`
+#[derive(Clone, Copy, PartialEq)]
+pub enum Filter {
+    /// Copy whichever source pixel is closest. Blocky but sharp.
+    Nearest,
+    /// Blend the nearest source pixels when growing. When shrinking every
+    /// source pixel that's covered gets averaged in, so thumbnails don't
+    /// sparkle.
+    Bilinear,
+}
+
+/// Resampling works in fixed point: one source pixel is this many steps
+const STEPS: u64 = 256;
+
+/// Stretch a line of samples out to `len` by blending the two closest ones
+fn lerp_line<const N: usize>(src: &[[u64; N]], len: usize) -> Vec<[u64; N]> {
+    let src_len = src.len() as u64;
+    let mut out = vec![[0; N]; len];
+    for (i, px) in out.iter_mut().enumerate() {
+        // pixel centers are at +0.5, which is where the 2s come from
+        let center = ((2 * i as u64 + 1) * src_len * STEPS) / (2 * len as u64);
+        let pos = cmp::min(center.saturating_sub(STEPS / 2), (src_len - 1) * STEPS);
+        let left = (pos / STEPS) as usize;
+        let right = cmp::min(left + 1, src.len() - 1);
+        let frac = pos % STEPS;
+        for c in 0..N {
+            let mixed = (src[left][c] * (STEPS - frac)) + (src[right][c] * frac);
+            px[c] = (mixed + (STEPS / 2)) / STEPS;
+        }
+    }
+    return out;
+}
+
+/// Resize a line of samples to `len` by averaging whatever part of `src` each
+/// output sample covers, weighted by how much of it is covered
+fn box_line<const N: usize>(src: &[[u64; N]], len: usize) -> Vec<[u64; N]> {
+    let src_len = src.len() as u64;
+    let mut out = vec![[0; N]; len];
+    for (i, px) in out.iter_mut().enumerate() {
+        let start = (i as u64 * src_len * STEPS) / len as u64;
+        let end = cmp::max(((i as u64 + 1) * src_len * STEPS) / len as u64, start + 1);
+        let mut sum = [0; N];
+        let mut total = 0;
+        for j in (start / STEPS)..((end + STEPS - 1) / STEPS) {
+            let weight = cmp::min(end, (j + 1) * STEPS) - cmp::max(start, j * STEPS);
+            for c in 0..N {
+                sum[c] += src[j as usize][c] * weight;
+            }
+            total += weight;
+        }
+        for c in 0..N {
+            px[c] = (sum[c] + (total / 2)) / total;
+        }
+    }
+    return out;
+}
+
+/// `lerp_line()` to grow, `box_line()` to shrink
+fn bilinear_line<const N: usize>(src: &[[u64; N]], len: usize) -> Vec<[u64; N]> {
+    if len > src.len() {
+        return lerp_line(src, len);
+    }
+    return box_line(src, len);
+}
+
+/// Something that resizes one line of samples, e.g. `box_line`
+type LineResampler<const N: usize> = fn(&[[u64; N]], usize) -> Vec<[u64; N]>;
+
+/// Resize a grid of samples `cols` wide to `dimensions` (x, y), one row at a
+/// time and then one column at a time
+fn resample<const N: usize>(
+    src: &[[u64; N]],
+    cols: usize,
+    dimensions: (usize, usize),
+    line: LineResampler<N>,
+) -> Vec<[u64; N]> {
+    let rows = src.len() / cols;
+    let (new_cols, new_rows) = dimensions;
+
+    let mut wide: Vec<[u64; N]> = Vec::with_capacity(new_cols * rows);
+    for row in src.chunks(cols) {
+        wide.extend(line(row, new_cols));
+    }
+
+    let mut out = vec![[0; N]; new_cols * new_rows];
+    let mut column = vec![[0; N]; rows];
+    for x in 0..new_cols {
+        for y in 0..rows {
+            column[y] = wide[(y * new_cols) + x];
+        }
+        for (y, px) in line(&column, new_rows).into_iter().enumerate() {
+            out[(y * new_cols) + x] = px;
+        }
+    }
+    return out;
+}
+
+#[derive(Clone, Copy)]
+/// RGBA pixel in a Bitmap. `alpha` is how opaque `color` is: 0 gets skipped,
+/// 255 replaces whatever was underneath, anything in between is blended.
+pub struct Pixel {
+    color: [u8; 3],
+    alpha: u8,
+}
+
+impl Pixel {
+    /// Simple helper for getting empty `Pixel`s to initialize new `Bitmap`s.
+    pub const fn empty() -> Pixel {
+        Pixel {
+            color: [0, 0, 0],
+            alpha: 0,
+        }
+    }
+
+    /// Create a new `Pixel`
+    pub const fn new(color: [u8; 3], alpha: u8) -> Pixel {
+        Pixel {
+            color: color,
+            alpha: alpha,
+        }
+    }
+
+    /// Helper to take a `Vec<u8>` and turn it into something `Bitmap` can take.
+    /// It's easier to create things like bitmap fonts as a `Vec<u8>` than as
+    /// `Pixel`s. 1s are opaque `color`, everything else is transparent.
+    pub fn from_u8_vec(v: Vec<u8>, color: [u8; 3]) -> Box<[Pixel]> {
+        let new_v: Vec<Pixel> = v
+            .iter()
+            .map(|p| {
+                Pixel::new(
+                    color,
+                    match p {
+                        1 => 255,
+                        _ => 0,
+                    },
+                )
+            })
+            .collect();
+        return new_v.into_boxed_slice();
+    }
+}
+
+/// A box of pixels that knows how to draw itself.
+/// rows: pixels in the `Bitmap` (y)
+/// cols: pixels in the `Bitmap` (x)
+/// bmp: the actual data of the `Bitmap`, as a flat array
+pub struct Bitmap {
+    rows: usize,
+    cols: usize,
+    bmp: Box<[Pixel]>,
+    border: Option<[u8; 3]>,
+}
+
+impl Bitmap {
+    pub const fn new(
+        rows: usize,
+        cols: usize,
+        bmp: Box<[Pixel]>,
+        border: Option<[u8; 3]>,
+    ) -> Bitmap {
+        Bitmap {
+            rows: rows,
+            cols: cols,
+            bmp: bmp,
+            border: border,
+        }
+    }
+
+    /// (x, y) size of the `Bitmap` in px
+    pub fn dimensions(&self) -> (usize, usize) {
+        return (self.cols, self.rows);
+    }
+
+    /// Scale by `factor`, which doesn't have to be whole. Useful for setting
+    /// the size of a picture relative to its original.
+    pub fn scale(&self, factor: Scale, filter: Filter) -> Bitmap {
+        return self.resize((factor.apply(self.cols), factor.apply(self.rows)), filter);
+    }
+
+    /// A copy stretched or squashed to `dimensions` (x, y). Doesn't keep the
+    /// aspect ratio; that's up to the caller.
+    pub fn resize(&self, dimensions: (usize, usize), filter: Filter) -> Bitmap {
+        let (new_cols, new_rows) = dimensions;
+        if new_cols * new_rows == 0 || self.cols * self.rows == 0 {
+            let empty = vec![Pixel::empty(); new_cols * new_rows];
+            return Bitmap::new(new_rows, new_cols, empty.into_boxed_slice(), None);
+        }
+
+        let resized: Vec<Pixel> = match filter {
+            Filter::Nearest => {
+                let mut resized = vec![Pixel::empty(); new_rows * new_cols];
+                for y in 0..new_rows {
+                    let src_y = y * self.rows / new_rows;
+                    for x in 0..new_cols {
+                        let src_x = x * self.cols / new_cols;
+                        resized[(y * new_cols) + x] = self.bmp[(src_y * self.cols) + src_x];
+                    }
+                }
+                resized
+            }
+            Filter::Bilinear => {
+                // premultiplied so transparent pixels don't bleed their color
+                // into the ones next to them
+                let premultiplied: Vec<[u64; 4]> = self
+                    .bmp
+                    .iter()
+                    .map(|px| {
+                        let alpha = u64::from(px.alpha);
+                        [
+                            u64::from(px.color[0]) * alpha,
+                            u64::from(px.color[1]) * alpha,
+                            u64::from(px.color[2]) * alpha,
+                            alpha,
+                        ]
+                    })
+                    .collect();
+                resample(&premultiplied, self.cols, dimensions, bilinear_line)
+                    .iter()
+                    .map(|px| {
+                        let alpha = px[3];
+                        if alpha == 0 {
+                            return Pixel::empty();
+                        }
+                        let mut color: Color = [0; 3];
+                        for c in 0..3 {
+                            color[c] = cmp::min((px[c] + (alpha / 2)) / alpha, 255) as u8;
+                        }
+                        Pixel::new(color, alpha as u8)
+                    })
+                    .collect()
+            }
+        };
+        return Bitmap::new(new_rows, new_cols, resized.into_boxed_slice(), None);
+    }
+
+    /// A copy turned clockwise by `rotation`
+    pub fn rotate(&self, rotation: Rotation) -> Bitmap {
+        let (cols, rows) = rotation.logical_size(self.dimensions());
+        let mut rotated = vec![Pixel::empty(); cols * rows];
+        for y in 0..self.rows {
+            for x in 0..self.cols {
+                let (to_x, to_y) = rotation.to_physical((x, y), self.dimensions());
+                rotated[(to_y * cols) + to_x] = self.bmp[(y * self.cols) + x];
+            }
+        }
+        return Bitmap::new(rows, cols, rotated.into_boxed_slice(), None);
+    }
+
+    /// Write the `Bitmap` to a `RenderTarget`. Pixels outside of `clip` or off
+    /// the edge of `target` are skipped.
+    /// encoder: converts colors to whatever `target` stores
+    /// start: (x, y) coordinates (in px) of the top-left corner of the `Bitmap`
+    /// clip: the only part of `target` that may be drawn on
+    pub fn draw(
+        &mut self,
+        target: &mut dyn RenderTarget,
+        encoder: &PixelEncoder,
+        start: (usize, usize),
+        clip: Rect,
+    ) {
+        unsafe {
+            // accessing a mutable static
+            if self.border.is_none() && DEBUG_BORDER == true {
+                self.border = Some([255, 255, 255]);
+            }
+        }
+        if let Some(value) = self.border {
+            self.set_border(value);
+        }
+
+        let bounds = Rect::new((0, 0), target.resolution());
+        let visible = Rect::new(start, self.dimensions())
+            .intersection(&bounds)
+            .and_then(|r| r.intersection(&clip));
+        let visible = match visible {
+            Some(value) => value,
+            None => return,
+        };
+
+        for y in (visible.y - start.1)..(visible.bottom() - start.1) {
+            for x in (visible.x - start.0)..(visible.right() - start.0) {
+                let px = &self.bmp[(self.cols * y) + x];
+                let pos = (x + start.0, y + start.1);
+                match px.alpha {
+                    0 => {}
+                    255 => {
+                        target.write_pixel(pos, encoder.encode(px.color));
+                    }
+                    alpha => {
+                        let under = encoder.decode(target.read_pixel(pos));
+                        let value = encoder.encode(blend(under, px.color, alpha));
+                        target.write_pixel(pos, value);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Overwrite the color on perimeter pixels to make a border.
+    pub fn set_border(&mut self, color: [u8; 3]) {
+        let last_row_offset = self.cols * (self.rows - 1);
+        for i in 0..self.cols {
+            self.bmp[i].alpha = 255;
+            self.bmp[i].color = color;
+
+            self.bmp[i + last_row_offset].alpha = 255;
+            self.bmp[i + last_row_offset].color = color;
+        }
+
+        for i in 0..self.rows {
+            self.bmp[i * self.cols].alpha = 255;
+            self.bmp[i * self.cols].color = color;
+
+            self.bmp[i * self.cols + self.cols - 1].alpha = 255;
+            self.bmp[i * self.cols + self.cols - 1].color = color;
+        }
+    }
+}
+
+/// A 1-bit-per-pixel bitmap, for glyphs. Doesn't have a color of its own;
+/// whatever draws it picks one. Rows are packed 8 pixels to a byte, leftmost
+/// pixel in the most significant bit, and every row starts on a fresh byte.
+pub struct MonoBitmap {
+    rows: usize,
+    cols: usize,
+    bits: Box<[u8]>,
+}
+
+impl MonoBitmap {
+    /// `bits` is `rows` rows of `(cols + 7) / 8` bytes each
+    pub fn new(rows: usize, cols: usize, bits: Box<[u8]>) -> MonoBitmap {
+        assert!(bits.len() == rows * MonoBitmap::row_bytes(cols));
+        MonoBitmap {
+            rows: rows,
+            cols: cols,
+            bits: bits,
+        }
+    }
+
+    /// All unset
+    pub fn empty(rows: usize, cols: usize) -> MonoBitmap {
+        let bits = vec![0; rows * MonoBitmap::row_bytes(cols)];
+        return MonoBitmap::new(rows, cols, bits.into_boxed_slice());
+    }
+
+    /// Pack rows of up to 16 pixels, one per `u16` with the leftmost pixel in
+    /// bit `cols - 1`.
+    pub fn from_rows(cols: usize, rows: &[u16]) -> MonoBitmap {
+        let row_bytes = MonoBitmap::row_bytes(cols);
+        let mut bits = vec![0; rows.len() * row_bytes];
+        for (y, row) in rows.iter().enumerate() {
+            for x in 0..cols {
+                if (row >> (cols - 1 - x)) & 1 == 1 {
+                    bits[(y * row_bytes) + (x / 8)] |= 0x80 >> (x % 8);
+                }
+            }
+        }
+        return MonoBitmap::new(rows.len(), cols, bits.into_boxed_slice());
+    }
+
+    fn row_bytes(cols: usize) -> usize {
+        return (cols + 7) / 8;
+    }
+
+    /// (x, y) size of the `MonoBitmap` in px
+    pub fn dimensions(&self) -> (usize, usize) {
+        return (self.cols, self.rows);
+    }
+
+    /// Whether the pixel at (`x`, `y`) is set
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        let byte = self.bits[(y * MonoBitmap::row_bytes(self.cols)) + (x / 8)];
+        return byte & (0x80 >> (x % 8)) != 0;
+    }
+
+    /// Set the pixel at (`x`, `y`)
+    pub fn set(&mut self, x: usize, y: usize) {
+        self.bits[(y * MonoBitmap::row_bytes(self.cols)) + (x / 8)] |= 0x80 >> (x % 8);
+    }
+
+    /// Blow every pixel up into a `factor` x `factor` square.
+    pub fn scale(&self, factor: usize) -> MonoBitmap {
+        let cols = self.cols * factor;
+        let rows = self.rows * factor;
+        let row_bytes = MonoBitmap::row_bytes(cols);
+        let mut bits = vec![0; rows * row_bytes];
+        for y in 0..rows {
+            for x in 0..cols {
+                if self.get(x / factor, y / factor) {
+                    bits[(y * row_bytes) + (x / 8)] |= 0x80 >> (x % 8);
+                }
+            }
+        }
+        return MonoBitmap::new(rows, cols, bits.into_boxed_slice());
+    }
+
+    /// Resize to `dimensions` (x, y), shading each pixel by how much of it
+    /// the set pixels cover. For sizes that aren't a whole multiple, where
+    /// `scale()` would make some strokes fatter than others.
+    pub fn smooth(&self, dimensions: (usize, usize)) -> AlphaBitmap {
+        let (cols, rows) = dimensions;
+        if cols * rows == 0 || self.cols * self.rows == 0 {
+            return AlphaBitmap::new(rows, cols, vec![0; cols * rows].into_boxed_slice());
+        }
+        let mut coverage: Vec<[u64; 1]> = Vec::with_capacity(self.cols * self.rows);
+        for y in 0..self.rows {
+            for x in 0..self.cols {
+                coverage.push([if self.get(x, y) { 255 } else { 0 }]);
+            }
+        }
+        let alpha: Vec<u8> = resample(&coverage, self.cols, dimensions, box_line)
+            .iter()
+            .map(|px| px[0] as u8)
+            .collect();
+        return AlphaBitmap::new(rows, cols, alpha.into_boxed_slice());
+    }
+
+    /// Write every set pixel to `target` as `value`, which is already encoded
+    /// for it. Unset pixels are left alone. Pixels outside of `clip` or off the
+    /// edge of `target` are skipped.
+    /// start: (x, y) coordinates (in px) of the top-left corner
+    pub fn draw(
+        &self,
+        target: &mut dyn RenderTarget,
+        value: u32,
+        start: (usize, usize),
+        clip: Rect,
+    ) {
+        let bounds = Rect::new((0, 0), target.resolution());
+        let visible = Rect::new(start, self.dimensions())
+            .intersection(&bounds)
+            .and_then(|r| r.intersection(&clip));
+        let visible = match visible {
+            Some(value) => value,
+            None => return,
+        };
+
+        for y in (visible.y - start.1)..(visible.bottom() - start.1) {
+            for x in (visible.x - start.0)..(visible.right() - start.0) {
+                if self.get(x, y) {
+                    target.write_pixel((x + start.0, y + start.1), value);
+                }
+            }
+        }
+    }
+}
+
+/// How much of each pixel is covered, 0 to 255. What `MonoBitmap::smooth()`
+/// makes. Like `MonoBitmap` it doesn't have a color of its own.
+pub struct AlphaBitmap {
+    rows: usize,
+    cols: usize,
+    alpha: Box<[u8]>,
+}
+
+impl AlphaBitmap {
+    pub fn new(rows: usize, cols: usize, alpha: Box<[u8]>) -> AlphaBitmap {
+        assert!(alpha.len() == rows * cols);
+        AlphaBitmap {
+            rows: rows,
+            cols: cols,
+            alpha: alpha,
+        }
+    }
+
+    /// (x, y) size of the `AlphaBitmap` in px
+    pub fn dimensions(&self) -> (usize, usize) {
+        return (self.cols, self.rows);
+    }
+
+    /// Blend `color` onto `target` as much as each pixel is covered. Pixels
+    /// outside of `clip` or off the edge of `target` are skipped.
+    /// encoder: converts colors to whatever `target` stores
+    /// start: (x, y) coordinates (in px) of the top-left corner
+    pub fn draw(
+        &self,
+        target: &mut dyn RenderTarget,
+        encoder: &PixelEncoder,
+        color: Color,
+        start: (usize, usize),
+        clip: Rect,
+    ) {
+        let bounds = Rect::new((0, 0), target.resolution());
+        let visible = Rect::new(start, self.dimensions())
+            .intersection(&bounds)
+            .and_then(|r| r.intersection(&clip));
+        let visible = match visible {
+            Some(value) => value,
+            None => return,
+        };
+
+        let value = encoder.encode(color);
+        for y in (visible.y - start.1)..(visible.bottom() - start.1) {
+            for x in (visible.x - start.0)..(visible.right() - start.0) {
+                let pos = (x + start.0, y + start.1);
+                match self.alpha[(self.cols * y) + x] {
+                    0 => {}
+                    255 => target.write_pixel(pos, value),
+                    alpha => {
+                        let under = encoder.decode(target.read_pixel(pos));
+                        target.write_pixel(pos, encoder.encode(blend(under, color, alpha)));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::core::render_target::MemoryTarget;
+
+    #[test]
+    fn draw_skips_empty_pixels() {
+        let mut target = MemoryTarget::new((4, 3));
+        let mut bitmap = Bitmap::new(2, 2, Pixel::from_u8_vec(vec![1, 0, 0, 1], [1, 2, 3]), None);
+        let encoder = PixelEncoder::for_target(&target);
+        bitmap.draw(&mut target, &encoder, (1, 1), Rect::new((0, 0), (4, 3)));
+        #[rustfmt::skip]
+        let expected = [
+            0, 0,        0,        0,
+            0, 0x010203, 0,        0,
+            0, 0,        0x010203, 0,
+        ];
+        assert_eq!(target.pixels(), &expected[..]);
+    }
+
+    #[test]
+    fn draw_border() {
+        let mut target = MemoryTarget::new((3, 3));
+        let mut bitmap = Bitmap::new(
+            3,
+            3,
+            Pixel::from_u8_vec(vec![0; 9], [0, 0, 0]),
+            Some([9, 0, 0]),
+        );
+        let encoder = PixelEncoder::for_target(&target);
+        bitmap.draw(&mut target, &encoder, (0, 0), Rect::new((0, 0), (3, 3)));
+        #[rustfmt::skip]
+        let expected = [
+            0x090000, 0x090000, 0x090000,
+            0x090000, 0,        0x090000,
+            0x090000, 0x090000, 0x090000,
+        ];
+        assert_eq!(target.pixels(), &expected[..]);
+    }
+
+    #[test]
+    fn draw_stays_inside_clip_and_target() {
+        let mut target = MemoryTarget::new((3, 3));
+        let encoder = PixelEncoder::for_target(&target);
+        let mut bitmap = Bitmap::new(3, 3, Pixel::from_u8_vec(vec![1; 9], [0, 0, 1]), None);
+        // hangs off the bottom-right and the clip cuts off the top row too
+        bitmap.draw(&mut target, &encoder, (1, 0), Rect::new((0, 1), (10, 10)));
+        #[rustfmt::skip]
+        let expected = [
+            0, 0, 0,
+            0, 1, 1,
+            0, 1, 1,
+        ];
+        assert_eq!(target.pixels(), &expected[..]);
+    }
+
+    #[test]
+    fn draw_blends_translucent_pixels() {
+        let mut target = MemoryTarget::new((2, 1));
+        let encoder = PixelEncoder::for_target(&target);
+        target.write_pixel((0, 0), encoder.encode([0, 0, 255]));
+        target.write_pixel((1, 0), encoder.encode([0, 0, 255]));
+        let pixels = vec![Pixel::new([255, 0, 0], 128), Pixel::new([255, 0, 0], 0)];
+        let mut bitmap = Bitmap::new(1, 2, pixels.into_boxed_slice(), None);
+        bitmap.draw(&mut target, &encoder, (0, 0), Rect::new((0, 0), (2, 1)));
+        assert_eq!(encoder.decode(target.pixels()[0]), [128, 0, 127]);
+        // fully transparent pixels don't touch the target
+        assert_eq!(encoder.decode(target.pixels()[1]), [0, 0, 255]);
+    }
+
+    #[test]
+    fn bitmap_scale_repeats_pixels() {
+        let pixels = Pixel::from_u8_vec(vec![1, 0], [1, 2, 3]);
+        let mut scaled = Bitmap::new(1, 2, pixels, None).scale(Scale::whole(2), Filter::Nearest);
+        assert_eq!(scaled.dimensions(), (4, 2));
+        let mut target = MemoryTarget::new((4, 2));
+        let encoder = PixelEncoder::for_target(&target);
+        scaled.draw(&mut target, &encoder, (0, 0), Rect::new((0, 0), (4, 2)));
+        #[rustfmt::skip]
+        let expected = [
+            0x010203, 0x010203, 0, 0,
+            0x010203, 0x010203, 0, 0,
+        ];
+        assert_eq!(target.pixels(), &expected[..]);
+    }
+
+    #[test]
+    fn mono_rows_pack_leftmost_first() {
+        // wide enough that each row takes two bytes
+        let glyph = MonoBitmap::from_rows(10, &[0b1000000001, 0b0100000000]);
+        assert_eq!(glyph.dimensions(), (10, 2));
+        assert_eq!(&glyph.bits[..], &[0x80, 0x40, 0x40, 0x00]);
+        assert!(glyph.get(0, 0) && glyph.get(9, 0) && glyph.get(1, 1));
+        assert!(!glyph.get(1, 0) && !glyph.get(0, 1));
+    }
+
+    #[test]
+    fn mono_scale() {
+        let glyph = MonoBitmap::from_rows(2, &[0b10, 0b01]).scale(3);
+        assert_eq!(glyph.dimensions(), (6, 6));
+        for y in 0..6 {
+            for x in 0..6 {
+                assert_eq!(glyph.get(x, y), (x < 3) == (y < 3));
+            }
+        }
+    }
+
+    #[test]
+    fn mono_draw_only_touches_set_pixels_inside_clip() {
+        let mut target = MemoryTarget::new((4, 3));
+        target.fill((0, 0), (4, 3), 7);
+        let glyph = MonoBitmap::from_rows(3, &[0b111, 0b101, 0b111]);
+        // the clip cuts off the left column
+        glyph.draw(&mut target, 1, (1, 1), Rect::new((2, 0), (10, 10)));
+        #[rustfmt::skip]
+        let expected = [
+            7, 7, 7, 7,
+            7, 7, 1, 1,
+            7, 7, 7, 1,
+        ];
+        assert_eq!(target.pixels(), &expected[..]);
+    }
+
+    #[test]
+    fn rotate() {
+        let (a, b) = (Pixel::new([1, 1, 1], 255), Pixel::new([2, 2, 2], 255));
+        // a 2x1 bitmap, `a` on the left
+        let bitmap = Bitmap::new(1, 2, vec![a, b].into_boxed_slice(), None);
+        let colors = |bitmap: Bitmap| -> Vec<Color> {
+            let mut target = MemoryTarget::new(bitmap.dimensions());
+            let encoder = PixelEncoder::for_target(&target);
+            let clip = Rect::new((0, 0), bitmap.dimensions());
+            let mut bitmap = bitmap;
+            bitmap.draw(&mut target, &encoder, (0, 0), clip);
+            return target
+                .pixels()
+                .iter()
+                .map(|px| encoder.decode(*px))
+                .collect();
+        };
+        let rotated = bitmap.rotate(Rotation::Deg90);
+        assert_eq!(rotated.dimensions(), (1, 2));
+        // turned clockwise the left end ends up on top
+        assert_eq!(colors(rotated), [[1, 1, 1], [2, 2, 2]]);
+        assert_eq!(
+            colors(bitmap.rotate(Rotation::Deg180)),
+            [[2, 2, 2], [1, 1, 1]]
+        );
+        assert_eq!(
+            colors(bitmap.rotate(Rotation::Deg270)),
+            [[2, 2, 2], [1, 1, 1]]
+        );
+        assert_eq!(
+            colors(bitmap.rotate(Rotation::Deg0)),
+            [[1, 1, 1], [2, 2, 2]]
+        );
+    }
+}
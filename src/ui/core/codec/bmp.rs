@@ -0,0 +1,209 @@
+use no_std_compat::prelude::v1::vec;
+use no_std_compat::vec::Vec;
+
+use crate::bitmap::{Bitmap, Pixel};
+use crate::graphics::Color;
+
+/// BITMAPFILEHEADER
+const FILE_HEADER_SIZE: usize = 14;
+/// BITMAPINFOHEADER, the one everything can read
+const INFO_HEADER_SIZE: usize = 40;
+
+/// Encode `pixels` (row after row from the top, `dimensions` is (x, y)) as a
+/// 24-bit uncompressed Windows BMP file.
+pub fn encode(dimensions: (usize, usize), pixels: &[Color]) -> Vec<u8> {
+    let (width, height) = dimensions;
+    assert!(pixels.len() == width * height);
+
+    // rows are padded out to a multiple of 4 bytes
+    let row_size = ((width * 3) + 3) & !3;
+    let data_offset = FILE_HEADER_SIZE + INFO_HEADER_SIZE;
+    let file_size = data_offset + (row_size * height);
+
+    let mut out: Vec<u8> = Vec::with_capacity(file_size);
+    out.extend_from_slice(b"BM");
+    out.extend_from_slice(&(file_size as u32).to_le_bytes());
+    out.extend_from_slice(&[0; 4]); // reserved
+    out.extend_from_slice(&(data_offset as u32).to_le_bytes());
+
+    out.extend_from_slice(&(INFO_HEADER_SIZE as u32).to_le_bytes());
+    out.extend_from_slice(&(width as i32).to_le_bytes());
+    // positive height means the rows are stored bottom-up
+    out.extend_from_slice(&(height as i32).to_le_bytes());
+    out.extend_from_slice(&1u16.to_le_bytes()); // planes
+    out.extend_from_slice(&24u16.to_le_bytes()); // bits per pixel
+    out.extend_from_slice(&0u32.to_le_bytes()); // BI_RGB, no compression
+    out.extend_from_slice(&((row_size * height) as u32).to_le_bytes());
+    out.extend_from_slice(&2835u32.to_le_bytes()); // 72 DPI, in px per meter
+    out.extend_from_slice(&2835u32.to_le_bytes());
+    out.extend_from_slice(&0u32.to_le_bytes()); // palette size
+    out.extend_from_slice(&0u32.to_le_bytes()); // important colors
+
+    for y in (0..height).rev() {
+        for [r, g, b] in &pixels[y * width..(y + 1) * width] {
+            out.extend_from_slice(&[*b, *g, *r]);
+        }
+        for _ in (width * 3)..row_size {
+            out.push(0);
+        }
+    }
+    return out;
+}
+
+/// BI_RGB: plain uncompressed pixels
+const COMPRESSION_NONE: u32 = 0;
+/// BI_BITFIELDS: uncompressed, with masks saying where each channel is
+const COMPRESSION_BITFIELDS: u32 = 3;
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    return Some(u16::from_le_bytes([bytes[0], bytes[1]]));
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    return Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+}
+
+/// Pull the channel under `mask` out of `value`, scaled to 8 bits
+fn extract(value: u32, mask: u32) -> u8 {
+    if mask == 0 {
+        return 0;
+    }
+    let shift = mask.trailing_zeros();
+    let max = u64::from(mask >> shift);
+    let channel = u64::from((value & mask) >> shift);
+    return (((channel * 255) + (max / 2)) / max) as u8;
+}
+
+/// Decode an uncompressed 24 or 32-bit Windows BMP. 32-bit files only get an
+/// alpha channel if their header says where it is (BI_BITFIELDS with an alpha
+/// mask); otherwise the fourth byte is padding and everything is opaque.
+pub fn decode(data: &[u8]) -> Result<Bitmap, &'static str> {
+    if data.get(0..2) != Some(b"BM") {
+        return Err("not a BMP");
+    }
+    let truncated = "truncated BMP";
+    let data_offset = read_u32(data, 10).ok_or(truncated)? as usize;
+    let header_size = read_u32(data, FILE_HEADER_SIZE).ok_or(truncated)? as usize;
+    if header_size < INFO_HEADER_SIZE {
+        return Err("unsupported BMP header");
+    }
+    let width = read_u32(data, FILE_HEADER_SIZE + 4).ok_or(truncated)? as i32;
+    let height = read_u32(data, FILE_HEADER_SIZE + 8).ok_or(truncated)? as i32;
+    let bpp = read_u16(data, FILE_HEADER_SIZE + 14).ok_or(truncated)?;
+    let compression = read_u32(data, FILE_HEADER_SIZE + 16).ok_or(truncated)?;
+    if width <= 0 || height == 0 {
+        return Err("empty BMP");
+    }
+    if bpp != 24 && bpp != 32 {
+        return Err("only 24 and 32-bit BMPs are supported");
+    }
+
+    // negative height means rows are stored top-down
+    let top_down = height < 0;
+    let (cols, rows) = (width as usize, height.unsigned_abs() as usize);
+
+    // masks for red, green, blue, alpha
+    let masks = match compression {
+        COMPRESSION_NONE => [0x00ff_0000, 0x0000_ff00, 0x0000_00ff, 0],
+        COMPRESSION_BITFIELDS if bpp == 32 => {
+            // right after BITMAPINFOHEADER, which is where newer headers keep
+            // them too. alpha is only there if the header is big enough
+            let at = FILE_HEADER_SIZE + INFO_HEADER_SIZE;
+            let alpha = if header_size >= INFO_HEADER_SIZE + 16 {
+                read_u32(data, at + 12).ok_or(truncated)?
+            } else {
+                0
+            };
+            [
+                read_u32(data, at).ok_or(truncated)?,
+                read_u32(data, at + 4).ok_or(truncated)?,
+                read_u32(data, at + 8).ok_or(truncated)?,
+                alpha,
+            ]
+        }
+        _ => return Err("compressed BMPs aren't supported"),
+    };
+
+    // every size and offset here comes from the header, so none of them get
+    // to overflow
+    let too_big = "BMP too big";
+    let bytes_per_px = usize::from(bpp / 8);
+    let row_size = cols
+        .checked_mul(bytes_per_px)
+        .and_then(|value| value.checked_add(3))
+        .ok_or(too_big)?
+        & !3;
+    let data_end = row_size
+        .checked_mul(rows)
+        .and_then(|value| value.checked_add(data_offset))
+        .ok_or(too_big)?;
+    if data.len() < data_end {
+        return Err(truncated);
+    }
+    let count = cols.checked_mul(rows).ok_or(too_big)?;
+
+    let mut pixels = vec![Pixel::empty(); count];
+    for row in 0..rows {
+        let y = if top_down { row } else { rows - 1 - row };
+        let start = row
+            .checked_mul(row_size)
+            .and_then(|value| value.checked_add(data_offset))
+            .ok_or(too_big)?;
+        for x in 0..cols {
+            let at = x
+                .checked_mul(bytes_per_px)
+                .and_then(|value| value.checked_add(start))
+                .ok_or(too_big)?;
+            let value = if bytes_per_px == 4 {
+                read_u32(data, at).ok_or(truncated)?
+            } else {
+                let bytes = data.get(at..at + 3).ok_or(truncated)?;
+                u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0])
+            };
+            let color = [
+                extract(value, masks[0]),
+                extract(value, masks[1]),
+                extract(value, masks[2]),
+            ];
+            let alpha = if masks[3] == 0 {
+                255
+            } else {
+                extract(value, masks[3])
+            };
+            pixels[(y * cols) + x] = Pixel::new(color, alpha);
+        }
+    }
+    return Ok(Bitmap::new(rows, cols, pixels.into_boxed_slice(), None));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_writes_rows_bottom_up_and_padded() {
+        let pixels = [[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]];
+        let out = encode((2, 2), &pixels);
+        // 2 px is 6 bytes of color, padded to 8
+        assert_eq!(out.len(), FILE_HEADER_SIZE + INFO_HEADER_SIZE + 16);
+        assert_eq!(&out[0..2], b"BM");
+        assert_eq!(read_u32(&out, 2).unwrap() as usize, out.len());
+        assert_eq!(
+            read_u32(&out, 10).unwrap() as usize,
+            FILE_HEADER_SIZE + INFO_HEADER_SIZE
+        );
+        assert_eq!(
+            (read_u32(&out, 18).unwrap(), read_u32(&out, 22).unwrap()),
+            (2, 2)
+        );
+        let data = &out[FILE_HEADER_SIZE + INFO_HEADER_SIZE..];
+        #[rustfmt::skip]
+        let expected = [
+            9, 8, 7, 12, 11, 10, 0, 0,
+            3, 2, 1, 6, 5, 4, 0, 0,
+        ];
+        assert_eq!(data, &expected[..]);
+    }
+}
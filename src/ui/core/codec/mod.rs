@@ -0,0 +1,173 @@
+//! Image file formats
+
+use crate::bitmap::Bitmap;
+
+pub mod bmp;
+pub mod qoi;
+
+/// Decode whichever supported format `data` is, going by its magic bytes
+pub fn decode(data: &[u8]) -> Result<Bitmap, &'static str> {
+    if data.starts_with(b"BM") {
+        return bmp::decode(data);
+    }
+    if data.starts_with(b"qoif") {
+        return qoi::decode(data);
+    }
+    return Err("unknown image format");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::Color;
+    use crate::ui::core::rect::Rect;
+    use crate::ui::core::render_target::{MemoryTarget, PixelEncoder, RenderTarget};
+    use no_std_compat::vec::Vec;
+
+    /// What's left where nothing opaque was drawn
+    const UNDER: Color = [1, 2, 3];
+
+    /// Draw `bitmap` onto `UNDER` and read every pixel back, row after row
+    fn draw(mut bitmap: Bitmap) -> Vec<Color> {
+        let mut target = MemoryTarget::new(bitmap.dimensions());
+        let encoder = PixelEncoder::for_target(&target);
+        target.fill((0, 0), bitmap.dimensions(), encoder.encode(UNDER));
+        let clip = Rect::new((0, 0), bitmap.dimensions());
+        bitmap.draw(&mut target, &encoder, (0, 0), clip);
+        return target
+            .pixels()
+            .iter()
+            .map(|px| encoder.decode(*px))
+            .collect();
+    }
+
+    fn qoi_header(cols: u32, rows: u32) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.extend_from_slice(b"qoif");
+        data.extend_from_slice(&cols.to_be_bytes());
+        data.extend_from_slice(&rows.to_be_bytes());
+        data.extend_from_slice(&[4, 0]);
+        return data;
+    }
+
+    #[test]
+    fn qoi_ops() {
+        let mut data = qoi_header(3, 2);
+        #[rustfmt::skip]
+        let ops = [
+            0xfe, 255, 0, 0,      // RGB red
+            0xc0,                 // RUN, one more
+            0xff, 0, 0, 255, 255, // RGBA blue
+            0x32,                 // INDEX 50, red's hash
+            0x5e,                 // DIFF -1, +1, 0
+            0xa2, 0x83,           // LUMA +2, +2, -3
+        ];
+        data.extend_from_slice(&ops);
+        data.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 1]);
+        let bitmap = decode(&data).expect("should decode");
+        assert_eq!(bitmap.dimensions(), (3, 2));
+        assert_eq!(
+            draw(bitmap),
+            [
+                [255, 0, 0],
+                [255, 0, 0],
+                [0, 0, 255],
+                [255, 0, 0],
+                [254, 1, 0],
+                [0, 3, 253],
+            ]
+        );
+    }
+
+    #[test]
+    fn qoi_transparent() {
+        let mut data = qoi_header(1, 1);
+        data.extend_from_slice(&[0xff, 9, 9, 9, 0]);
+        assert_eq!(draw(decode(&data).expect("should decode")), [UNDER]);
+    }
+
+    #[test]
+    fn qoi_truncated() {
+        let mut data = qoi_header(2, 2);
+        data.extend_from_slice(&[0xfe, 255, 0]);
+        assert!(decode(&data).is_err());
+        assert!(decode(&qoi_header(2, 2)[..10]).is_err());
+    }
+
+    #[test]
+    fn qoi_too_big_for_its_data() {
+        // would be 16 billion pixels if it were believed
+        let mut data = qoi_header(u32::MAX, u32::MAX);
+        data.extend_from_slice(&[0xfe, 255, 0, 0, 0xfd]);
+        assert!(decode(&data).is_err());
+        assert!(decode(&qoi_header(0, 5)).is_err());
+    }
+
+    #[test]
+    fn bmp_round_trip() {
+        let colors: [Color; 6] = [
+            [255, 0, 0],
+            [0, 255, 0],
+            [0, 0, 255],
+            [1, 2, 3],
+            [255, 255, 255],
+            [0, 0, 0],
+        ];
+        let data = bmp::encode((3, 2), &colors);
+        let bitmap = decode(&data).expect("should decode");
+        assert_eq!(bitmap.dimensions(), (3, 2));
+        assert_eq!(draw(bitmap), colors);
+    }
+
+    /// A top-down 32-bit BI_BITFIELDS BMP with an alpha mask
+    fn bmp_with_alpha(width: i32, height: i32, pixels: &[u32]) -> Vec<u8> {
+        let data_offset: u32 = 14 + 56;
+        let mut data: Vec<u8> = Vec::new();
+        data.extend_from_slice(b"BM");
+        data.extend_from_slice(&(data_offset + (4 * pixels.len() as u32)).to_le_bytes());
+        data.extend_from_slice(&[0; 4]);
+        data.extend_from_slice(&data_offset.to_le_bytes());
+        data.extend_from_slice(&56u32.to_le_bytes());
+        data.extend_from_slice(&width.to_le_bytes());
+        data.extend_from_slice(&(-height).to_le_bytes());
+        data.extend_from_slice(&1u16.to_le_bytes());
+        data.extend_from_slice(&32u16.to_le_bytes());
+        data.extend_from_slice(&3u32.to_le_bytes());
+        data.extend_from_slice(&[0; 20]);
+        for mask in [0x00ff_0000u32, 0x0000_ff00, 0x0000_00ff, 0xff00_0000] {
+            data.extend_from_slice(&mask.to_le_bytes());
+        }
+        for px in pixels {
+            data.extend_from_slice(&px.to_le_bytes());
+        }
+        return data;
+    }
+
+    #[test]
+    fn bmp_alpha_mask() {
+        let data = bmp_with_alpha(2, 1, &[0x00ff_0000, 0xff00_ff00]);
+        let bitmap = decode(&data).expect("should decode");
+        assert_eq!(draw(bitmap), [UNDER, [0, 255, 0]]);
+    }
+
+    #[test]
+    fn bmp_truncated() {
+        let data = bmp::encode((3, 2), &[[9, 9, 9]; 6]);
+        assert!(decode(&data[..data.len() - 1]).is_err());
+        assert!(decode(&data[..20]).is_err());
+    }
+
+    #[test]
+    fn bmp_too_big_for_its_data() {
+        let data = bmp_with_alpha(i32::MAX, i32::MAX, &[0; 4]);
+        assert!(decode(&data).is_err());
+        let data = bmp_with_alpha(i32::MAX, i32::MIN + 1, &[0; 4]);
+        assert!(decode(&data).is_err());
+    }
+
+    #[test]
+    fn unknown_format() {
+        assert!(decode(b"GIF89a").is_err());
+        assert!(decode(b"").is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use no_std_compat::prelude::v1::vec;
+
+use crate::bitmap::{Bitmap, Pixel};
+
+/// "qoif", width, height, channels, colorspace
+const HEADER_SIZE: usize = 14;
+
+const OP_RGB: u8 = 0xfe;
+const OP_RGBA: u8 = 0xff;
+// the rest are told apart by their top two bits
+const OP_INDEX: u8 = 0x00;
+const OP_DIFF: u8 = 0x40;
+const OP_LUMA: u8 = 0x80;
+const OP_RUN: u8 = 0xc0;
+const OP_MASK: u8 = 0xc0;
+/// The most pixels one byte can encode, as an `OP_RUN`
+const MAX_RUN: usize = 62;
+
+/// Decode a QOI ("Quite OK Image") file. See https://qoiformat.org for the
+/// spec; it's one page.
+pub fn decode(data: &[u8]) -> Result<Bitmap, &'static str> {
+    if data.len() < HEADER_SIZE || &data[0..4] != b"qoif" {
+        return Err("not a QOI image");
+    }
+    let cols = u32::from_be_bytes([data[4], data[5], data[6], data[7]]) as usize;
+    let rows = u32::from_be_bytes([data[8], data[9], data[10], data[11]]) as usize;
+    if cols == 0 || rows == 0 {
+        return Err("empty QOI image");
+    }
+    // don't trust the header with an allocation the data couldn't fill
+    let count = cols.checked_mul(rows).ok_or("QOI image too big")?;
+    if count > (data.len() - HEADER_SIZE).saturating_mul(MAX_RUN) {
+        return Err("truncated QOI image");
+    }
+
+    // [r, g, b, a] of recently seen pixels, indexed by a hash of the color
+    let mut seen = [[0u8; 4]; 64];
+    let mut px: [u8; 4] = [0, 0, 0, 255];
+    let mut pixels = vec![Pixel::empty(); count];
+    let mut at = HEADER_SIZE;
+    let mut run = 0;
+
+    for out in pixels.iter_mut() {
+        if run > 0 {
+            run -= 1;
+        } else {
+            let op = *data.get(at).ok_or("truncated QOI image")?;
+            at += 1;
+            if op == OP_RGB || op == OP_RGBA {
+                let channels = if op == OP_RGB { 3 } else { 4 };
+                let bytes = data.get(at..at + channels).ok_or("truncated QOI image")?;
+                px[..channels].copy_from_slice(bytes);
+                at += channels;
+            } else {
+                match op & OP_MASK {
+                    OP_INDEX => {
+                        px = seen[usize::from(op & 0x3f)];
+                    }
+                    OP_DIFF => {
+                        px[0] = px[0].wrapping_add((op >> 4) & 0x03).wrapping_sub(2);
+                        px[1] = px[1].wrapping_add((op >> 2) & 0x03).wrapping_sub(2);
+                        px[2] = px[2].wrapping_add(op & 0x03).wrapping_sub(2);
+                    }
+                    OP_LUMA => {
+                        let next = *data.get(at).ok_or("truncated QOI image")?;
+                        at += 1;
+                        let dg = (op & 0x3f).wrapping_sub(32);
+                        px[0] = px[0]
+                            .wrapping_add(dg)
+                            .wrapping_add(next >> 4)
+                            .wrapping_sub(8);
+                        px[1] = px[1].wrapping_add(dg);
+                        px[2] = px[2]
+                            .wrapping_add(dg)
+                            .wrapping_add(next & 0x0f)
+                            .wrapping_sub(8);
+                    }
+                    OP_RUN => {
+                        // this pixel plus however many more
+                        run = op & 0x3f;
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            let hash = (usize::from(px[0]) * 3
+                + usize::from(px[1]) * 5
+                + usize::from(px[2]) * 7
+                + usize::from(px[3]) * 11)
+                % 64;
+            seen[hash] = px;
+        }
+        *out = Pixel::new([px[0], px[1], px[2]], px[3]);
+    }
+    return Ok(Bitmap::new(rows, cols, pixels.into_boxed_slice(), None));
+}
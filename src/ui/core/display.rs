@@ -0,0 +1,347 @@
+use uefi::proto::console::gop::{GraphicsOutput, PixelBitmask, PixelFormat};
+use uefi::table::boot::BootServices;
+use uefi::ResultExt;
+
+use no_std_compat::cmp;
+use no_std_compat::prelude::v1::{vec, Box};
+use no_std_compat::vec::Vec;
+
+use crate::ui::core::render_target::{GopTarget, PixelEncoder, RenderTarget};
+
+/// Which display `Graphics` should treat as the main one
+#[derive(Clone, Copy, Debug)]
+#[allow(dead_code)]
+pub enum PrimaryDisplay {
+    /// Index into `displays()`
+    Index(usize),
+    /// The first display that has a mode with this resolution
+    Resolution((usize, usize)),
+}
+
+impl PrimaryDisplay {
+    /// Index of the display this picks, given the resolutions each display
+    /// offers
+    fn find(&self, modes: &[Vec<(usize, usize)>]) -> Option<usize> {
+        return match *self {
+            PrimaryDisplay::Index(idx) if idx < modes.len() => Some(idx),
+            PrimaryDisplay::Index(_) => None,
+            PrimaryDisplay::Resolution(resolution) => {
+                modes.iter().position(|modes| modes.contains(&resolution))
+            }
+        };
+    }
+}
+
+/// How to set up drawing on machines with more than one output
+pub struct DisplayOptions {
+    pub primary: PrimaryDisplay,
+    /// Show the same picture on every other display too. They get whatever
+    /// fits starting from their top-left corner.
+    pub mirror: bool,
+}
+
+impl Default for DisplayOptions {
+    /// First display only
+    fn default() -> DisplayOptions {
+        DisplayOptions {
+            primary: PrimaryDisplay::Index(0),
+            mirror: false,
+        }
+    }
+}
+
+/// One GOP instance the firmware knows about
+pub struct Display {
+    /// Resolution of the current mode
+    pub resolution: (usize, usize),
+    /// Resolutions of every mode it offers
+    pub modes: Vec<(usize, usize)>,
+    gop: &'static mut GraphicsOutput<'static>,
+}
+
+/// Every handle that supports `GraphicsOutput`. The console usually has a
+/// handle of its own pointing at the same GOP as some GPU; those only show up
+/// once.
+pub fn displays(boot_services: &BootServices) -> Vec<Display> {
+    let handles = boot_services
+        .find_handles::<GraphicsOutput>()
+        .expect_success("failed to find graphics handles");
+
+    let mut displays: Vec<Display> = Vec::new();
+    let mut seen: Vec<usize> = Vec::new();
+    for handle in handles {
+        let gop = match boot_services.handle_protocol::<GraphicsOutput>(handle) {
+            Ok(value) => value.unwrap(),
+            Err(_) => continue,
+        };
+        let addr = gop.get() as usize;
+        if seen.contains(&addr) {
+            continue;
+        }
+        seen.push(addr);
+
+        // same deal as `Application::new()`: this outlives the borrow of
+        // `BootServices` it came from
+        let gop = unsafe { &mut *gop.get() };
+        let mut modes: Vec<(usize, usize)> = Vec::new();
+        for mode in gop.modes() {
+            let resolution = mode.expect("failed to get mode").info().resolution();
+            if !modes.contains(&resolution) {
+                modes.push(resolution);
+            }
+        }
+        displays.push(Display {
+            resolution: gop.current_mode_info().resolution(),
+            modes: modes,
+            gop: gop,
+        });
+    }
+    return displays;
+}
+
+/// Pick the primary display according to `options` and make a `RenderTarget`
+/// out of it, mirrored to the rest if asked. Falls back to the first display
+/// if `options.primary` doesn't match anything.
+pub fn open(boot_services: &BootServices, options: &DisplayOptions) -> Box<dyn RenderTarget> {
+    let mut displays = displays(boot_services);
+    assert!(displays.len() > 0, "no displays");
+    for (i, display) in displays.iter().enumerate() {
+        log::info!(
+            "display {}: {}x{}, {} modes",
+            i,
+            display.resolution.0,
+            display.resolution.1,
+            display.modes.len()
+        );
+    }
+
+    let modes: Vec<Vec<(usize, usize)>> = displays
+        .iter()
+        .map(|display| display.modes.clone())
+        .collect();
+    let idx = match options.primary.find(&modes) {
+        Some(value) => value,
+        None => {
+            log::info!("no display matches {:?}, using the first", options.primary);
+            0
+        }
+    };
+
+    let primary = Box::from(GopTarget::new(displays.remove(idx).gop));
+    if !options.mirror || displays.len() == 0 {
+        return primary;
+    }
+    let mirrors: Vec<Box<dyn RenderTarget>> = displays
+        .into_iter()
+        .map(|display| Box::from(GopTarget::new(display.gop)) as Box<dyn RenderTarget>)
+        .collect();
+    return Box::from(MirrorTarget::new(primary, mirrors));
+}
+
+/// `RenderTarget` that copies everything drawn on `primary` to every mirror.
+/// `Graphics` only knows about `primary`: its resolution and pixel format are
+/// the ones reported, and pixels get converted for mirrors that store them
+/// differently.
+pub struct MirrorTarget {
+    primary: Box<dyn RenderTarget>,
+    mirrors: Vec<Box<dyn RenderTarget>>,
+}
+
+impl MirrorTarget {
+    pub fn new(
+        primary: Box<dyn RenderTarget>,
+        mirrors: Vec<Box<dyn RenderTarget>>,
+    ) -> MirrorTarget {
+        MirrorTarget {
+            primary: primary,
+            mirrors: mirrors,
+        }
+    }
+
+    /// Re-encode a `primary` pixel for `mirror`
+    fn convert(&self, value: u32, mirror: &dyn RenderTarget) -> u32 {
+        let color = PixelEncoder::for_target(self.primary.as_ref()).decode(value);
+        return PixelEncoder::for_target(mirror).encode(color);
+    }
+}
+
+impl RenderTarget for MirrorTarget {
+    fn resolution(&self) -> (usize, usize) {
+        return self.primary.resolution();
+    }
+
+    fn stride(&self) -> usize {
+        return self.primary.stride();
+    }
+
+    fn pixel_format(&self) -> PixelFormat {
+        return self.primary.pixel_format();
+    }
+
+    fn pixel_bitmask(&self) -> Option<PixelBitmask> {
+        return self.primary.pixel_bitmask();
+    }
+
+    fn available_resolutions(&self) -> Vec<(usize, usize)> {
+        return self.primary.available_resolutions();
+    }
+
+    /// Mirrors try to follow along. The ones that can't keep their mode and
+    /// show whatever part of the picture fits.
+    fn set_resolution(&mut self, resolution: (usize, usize)) -> bool {
+        if !self.primary.set_resolution(resolution) {
+            return false;
+        }
+        for mirror in self.mirrors.iter_mut() {
+            if !mirror.set_resolution(resolution) {
+                log::info!("mirror can't do {}x{}", resolution.0, resolution.1);
+            }
+        }
+        return true;
+    }
+
+    fn write_pixel(&mut self, pos: (usize, usize), value: u32) {
+        self.primary.write_pixel(pos, value);
+        for i in 0..self.mirrors.len() {
+            let (width, height) = self.mirrors[i].resolution();
+            if pos.0 < width && pos.1 < height {
+                let converted = self.convert(value, self.mirrors[i].as_ref());
+                self.mirrors[i].write_pixel(pos, converted);
+            }
+        }
+    }
+
+    fn read_pixel(&mut self, pos: (usize, usize)) -> u32 {
+        return self.primary.read_pixel(pos);
+    }
+
+    fn fill(&mut self, dest: (usize, usize), dims: (usize, usize), value: u32) {
+        self.primary.fill(dest, dims, value);
+        for i in 0..self.mirrors.len() {
+            let (width, height) = self.mirrors[i].resolution();
+            if dest.0 >= width || dest.1 >= height {
+                continue;
+            }
+            let dims = (
+                cmp::min(dims.0, width - dest.0),
+                cmp::min(dims.1, height - dest.1),
+            );
+            let converted = self.convert(value, self.mirrors[i].as_ref());
+            self.mirrors[i].fill(dest, dims, converted);
+        }
+    }
+
+    fn blit(
+        &mut self,
+        buffer: &[u32],
+        buffer_stride: usize,
+        src: (usize, usize),
+        dest: (usize, usize),
+        dims: (usize, usize),
+    ) {
+        self.primary.blit(buffer, buffer_stride, src, dest, dims);
+
+        let primary_encoder = PixelEncoder::for_target(self.primary.as_ref());
+        for mirror in self.mirrors.iter_mut() {
+            let (width, height) = mirror.resolution();
+            if dest.0 >= width || dest.1 >= height {
+                continue;
+            }
+            let dims = (
+                cmp::min(dims.0, width - dest.0),
+                cmp::min(dims.1, height - dest.1),
+            );
+            if mirror.pixel_format() == self.primary.pixel_format()
+                && mirror.pixel_bitmask() == self.primary.pixel_bitmask()
+            {
+                mirror.blit(buffer, buffer_stride, src, dest, dims);
+                continue;
+            }
+
+            let mirror_encoder = PixelEncoder::for_target(mirror.as_ref());
+            let mut converted = vec![0; dims.0 * dims.1];
+            for y in 0..dims.1 {
+                for x in 0..dims.0 {
+                    let value = buffer[((src.1 + y) * buffer_stride) + src.0 + x];
+                    converted[(y * dims.0) + x] =
+                        mirror_encoder.encode(primary_encoder.decode(value));
+                }
+            }
+            mirror.blit(&converted, dims.0, (0, 0), dest, dims);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::core::render_target::MemoryTarget;
+
+    const RED: [u8; 3] = [255, 0, 0];
+
+    /// A 4x2 `Bgr` primary, a 2x1 `Bgr` mirror and a 4x2 `Rgb` mirror
+    fn mirrored() -> MirrorTarget {
+        return MirrorTarget::new(
+            Box::new(MemoryTarget::new((4, 2))),
+            vec![
+                Box::new(MemoryTarget::new((2, 1))),
+                Box::new(MemoryTarget::with_format((4, 2), PixelFormat::Rgb, None)),
+            ],
+        );
+    }
+
+    fn color_at(target: &mut dyn RenderTarget, pos: (usize, usize)) -> [u8; 3] {
+        return PixelEncoder::for_target(target).decode(target.read_pixel(pos));
+    }
+
+    #[test]
+    fn picking_the_primary_display() {
+        let modes = vec![vec![(800, 600)], vec![(1024, 768), (800, 600)]];
+        assert_eq!(PrimaryDisplay::Index(1).find(&modes), Some(1));
+        assert_eq!(PrimaryDisplay::Index(2).find(&modes), None);
+        assert_eq!(PrimaryDisplay::Resolution((800, 600)).find(&modes), Some(0));
+        assert_eq!(
+            PrimaryDisplay::Resolution((1024, 768)).find(&modes),
+            Some(1)
+        );
+        assert_eq!(PrimaryDisplay::Resolution((640, 480)).find(&modes), None);
+    }
+
+    #[test]
+    fn mirrors_get_what_fits_in_their_own_format() {
+        let mut target = mirrored();
+        let value = PixelEncoder::for_target(&target).encode(RED);
+        target.write_pixel((3, 1), value);
+        target.fill((1, 0), (2, 1), value);
+        assert_eq!(color_at(&mut target, (3, 1)), RED);
+        assert_eq!(color_at(target.mirrors[0].as_mut(), (1, 0)), RED);
+        assert_eq!(color_at(target.mirrors[0].as_mut(), (0, 0)), [0, 0, 0]);
+        for pos in [(1, 0), (2, 0), (3, 1)] {
+            assert_eq!(color_at(target.mirrors[1].as_mut(), pos), RED);
+        }
+        // the `Rgb` mirror stores it differently from the primary
+        assert_ne!(target.mirrors[1].read_pixel((3, 1)), value);
+    }
+
+    #[test]
+    fn blits_are_cut_down_for_small_mirrors() {
+        let mut target = mirrored();
+        let value = PixelEncoder::for_target(&target).encode(RED);
+        let buffer = vec![value; 8];
+        target.blit(&buffer, 4, (0, 0), (0, 0), (4, 2));
+        assert_eq!(color_at(target.mirrors[0].as_mut(), (1, 0)), RED);
+        assert_eq!(color_at(target.mirrors[1].as_mut(), (3, 1)), RED);
+        assert_eq!(color_at(&mut target, (3, 1)), RED);
+    }
+
+    #[test]
+    fn mirrors_follow_resolution_changes() {
+        let mut target = mirrored();
+        assert!(target.set_resolution((6, 6)));
+        assert_eq!(target.resolution(), (6, 6));
+        assert!(target
+            .mirrors
+            .iter()
+            .all(|mirror| mirror.resolution() == (6, 6)));
+    }
+}
@@ -1,823 +0,0 @@
-pub const FONT_WIDTH: usize = 9;
-pub const FONT_HEIGHT: usize = 11;
-
-use crate::ui::core::bmp;
-
-/// Given a character, return a new `Bitmap` instance for it.
-/// A possible improvement is to fill out a `static mut` map of char -> `Bitmap`
-/// as letters are requested to reduce allocations
-pub fn get_bitmap(c: char, color: [u8; 3]) -> bmp::Bitmap {
-    match c.to_ascii_lowercase() {
-        'a' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(A.to_vec(), color),
-                None,
-            );
-        }
-        'b' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(B.to_vec(), color),
-                None,
-            );
-        }
-        'c' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(C.to_vec(), color),
-                None,
-            );
-        }
-        'd' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(D.to_vec(), color),
-                None,
-            );
-        }
-        'e' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(E.to_vec(), color),
-                None,
-            );
-        }
-        'f' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(F.to_vec(), color),
-                None,
-            );
-        }
-        'g' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(G.to_vec(), color),
-                None,
-            );
-        }
-        'h' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(H.to_vec(), color),
-                None,
-            );
-        }
-        'i' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(I.to_vec(), color),
-                None,
-            );
-        }
-        'j' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(J.to_vec(), color),
-                None,
-            );
-        }
-        'k' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(K.to_vec(), color),
-                None,
-            );
-        }
-        'l' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(L.to_vec(), color),
-                None,
-            );
-        }
-        'm' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(M.to_vec(), color),
-                None,
-            );
-        }
-        'n' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(N.to_vec(), color),
-                None,
-            );
-        }
-        'o' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(O.to_vec(), color),
-                None,
-            );
-        }
-        'p' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(P.to_vec(), color),
-                None,
-            );
-        }
-        'q' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(Q.to_vec(), color),
-                None,
-            );
-        }
-        'r' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(R.to_vec(), color),
-                None,
-            );
-        }
-        's' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(S.to_vec(), color),
-                None,
-            );
-        }
-        't' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(T.to_vec(), color),
-                None,
-            );
-        }
-        'u' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(U.to_vec(), color),
-                None,
-            );
-        }
-        'v' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(V.to_vec(), color),
-                None,
-            );
-        }
-        'w' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(W.to_vec(), color),
-                None,
-            );
-        }
-        'x' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(X.to_vec(), color),
-                None,
-            );
-        }
-        'y' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(Y.to_vec(), color),
-                None,
-            );
-        }
-        'z' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(Z.to_vec(), color),
-                None,
-            );
-        }
-        '1' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(ONE.to_vec(), color),
-                None,
-            );
-        }
-        '2' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(TWO.to_vec(), color),
-                None,
-            );
-        }
-        '3' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(THREE.to_vec(), color),
-                None,
-            );
-        }
-        '4' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(FOUR.to_vec(), color),
-                None,
-            );
-        }
-        '5' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(FIVE.to_vec(), color),
-                None,
-            );
-        }
-        '6' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(SIX.to_vec(), color),
-                None,
-            );
-        }
-        '7' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(SEVEN.to_vec(), color),
-                None,
-            );
-        }
-        '8' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(EIGHT.to_vec(), color),
-                None,
-            );
-        }
-        '9' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(NINE.to_vec(), color),
-                None,
-            );
-        }
-        '0' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(ZERO.to_vec(), color),
-                None,
-            );
-        }
-        '!' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(BANG.to_vec(), color),
-                None,
-            );
-        }
-        '?' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(QUESTION.to_vec(), color),
-                None,
-            );
-        }
-        '-' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(HYPHEN.to_vec(), color),
-                None,
-            );
-        }
-        '_' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(UNDERSCORE.to_vec(), color),
-                None,
-            );
-        }
-        ':' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(COLON.to_vec(), color),
-                None,
-            );
-        }
-        ';' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(SEMICOLON.to_vec(), color),
-                None,
-            );
-        }
-        ' ' | '\t' => {
-            // tabs are spaces now, the flame war can end
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(SPACE.to_vec(), color),
-                None,
-            );
-        }
-        '(' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(L_PAREN.to_vec(), color),
-                None,
-            );
-        }
-        ')' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(R_PAREN.to_vec(), color),
-                None,
-            );
-        }
-        '[' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(L_BRACKET.to_vec(), color),
-                None,
-            );
-        }
-        ']' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(R_BRACKET.to_vec(), color),
-                None,
-            );
-        }
-        '{' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(L_BRACE.to_vec(), color),
-                None,
-            );
-        }
-        '}' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(R_BRACE.to_vec(), color),
-                None,
-            );
-        }
-        '\'' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(APOSTROPHE.to_vec(), color),
-                None,
-            );
-        }
-        '"' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(QUOTE.to_vec(), color),
-                None,
-            );
-        }
-        '.' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(PERIOD.to_vec(), color),
-                None,
-            );
-        }
-        ',' => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(COMMA.to_vec(), color),
-                None,
-            );
-        }
-        _ => {
-            return bmp::Bitmap::new(
-                FONT_HEIGHT,
-                FONT_WIDTH,
-                bmp::Pixel::from_u8_vec(A.to_vec(), color),
-                None,
-            );
-        }
-    };
-}
-
-// most symbols from these links
-// https://www.google.com/url?sa=i&url=http%3A%2F%2Flegionfonts.com%2Ffonts%2Fberkelium-bitmap&psig=AOvVaw3BztSTcsP_NJKX3-2huFxr&ust=163374FONT_WIDTH74634FONT_WIDTH000&source=images&cd=vfe&ved=0CAsQjRxqFwoTCICfnJ7uufMCFQAAAAAdAAAAABAY
-// https://lh3.googleusercontent.com/proxy/eeQVpu6U1ULd27LXxJ8s-SNPBsGPw6nogrZfStRAZL0nr3oTPBt57Tn6DfxkBJCRVZNgMFONT_WIDTH_2E7QZ-Cp-FZTFONT_WIDTH0qCHAJfcd-Tt3yRKlZJwVL4xY4VQ3wVW_-4OImfFONT_WIDTHTsSVVe5TmQWU0Q
-// LegionFonts Berkelium bitmap font (free)
-//
-// i wrote them on a plane and the image was cut off so for some i improvised
-
-const A: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
-    0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
-    1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const B: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
-    1, 1, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0,
-    1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const C: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
-    0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0,
-    0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const D: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
-    1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0,
-    1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const E: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0,
-    0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
-    0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const F: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0,
-    0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
-    0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const G: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
-    0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0,
-    0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const H: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0,
-    0, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
-    1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const I: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1,
-    0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
-    0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const J: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1,
-    1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
-    0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const K: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1,
-    1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0,
-    1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const L: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1,
-    0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
-    0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const M: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0, 1, 1, 1, 0, 0, 1, 1, 1, 1,
-    1, 1, 1, 0, 0, 1, 1, 0, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
-    1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const N: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 1, 1,
-    0, 1, 1, 0, 0, 1, 1, 0, 1, 1, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
-    1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const O: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0,
-    1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0,
-    0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const P: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0,
-    1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
-    0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const Q: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
-    1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0,
-    0, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const R: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0,
-    1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 0, 0, 0, 0,
-    0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const S: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 0, 0, 1, 1, 0,
-    0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0,
-    0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const T: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1,
-    0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
-    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const U: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0,
-    1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0,
-    0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const V: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0,
-    1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0,
-    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const W: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0,
-    0, 1, 1, 0, 0, 1, 1, 0, 1, 0, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 0, 1, 1, 1, 0, 0,
-    0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const X: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 1,
-    1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0,
-    1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const Y: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0,
-    1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0,
-    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const Z: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1,
-    1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0,
-    0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const ONE: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1,
-    1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
-    0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const TWO: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0,
-    1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0,
-    0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const THREE: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0,
-    1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0,
-    0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const FOUR: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0,
-    1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0,
-    0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const FIVE: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1,
-    1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0,
-    0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const SIX: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0,
-    0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0,
-    0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const SEVEN: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1,
-    1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
-    0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const EIGHT: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
-    1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0,
-    0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const NINE: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
-    1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
-    0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const ZERO: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0,
-    1, 1, 0, 0, 0, 1, 1, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0,
-    0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const QUESTION: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0,
-    1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const BANG: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1,
-    0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const L_PAREN: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0,
-    0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
-    0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const R_PAREN: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0,
-    1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
-    0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const L_BRACKET: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1,
-    0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
-    0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const R_BRACKET: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1,
-    1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
-    0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const L_BRACE: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1,
-    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
-    0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const R_BRACE: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1,
-    0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0,
-    0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const COLON: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const SEMICOLON: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
-    0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const HYPHEN: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const UNDERSCORE: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const SPACE: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const APOSTROPHE: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const QUOTE: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0,
-    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const PERIOD: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
-    0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
-
-const COMMA: [u8; FONT_WIDTH * FONT_HEIGHT] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0,
-    0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0,
-];
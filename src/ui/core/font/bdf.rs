@@ -0,0 +1,337 @@
+use no_std_compat::cmp;
+use no_std_compat::collections::BTreeMap;
+use no_std_compat::str;
+use no_std_compat::vec::Vec;
+
+use crate::bitmap::MonoBitmap;
+use crate::ui::core::font::{Font, GlyphMetrics};
+
+/// (width, height, x offset, y offset) as in `FONTBOUNDINGBOX` and `BBX`.
+/// Offsets are from the origin, which sits on the baseline, y going up.
+type BoundingBox = (usize, usize, isize, isize);
+
+fn parse_numbers(args: &[&str]) -> Result<Vec<isize>, &'static str> {
+    return args
+        .iter()
+        .map(|arg| arg.parse::<isize>().map_err(|_| "bad number in BDF font"))
+        .collect();
+}
+
+fn parse_bounding_box(args: &[&str]) -> Result<BoundingBox, &'static str> {
+    let numbers = parse_numbers(args)?;
+    if numbers.len() != 4 || numbers[0] < 0 || numbers[1] < 0 {
+        return Err("bad bounding box in BDF font");
+    }
+    return Ok((
+        numbers[0] as usize,
+        numbers[1] as usize,
+        numbers[2],
+        numbers[3],
+    ));
+}
+
+/// One glyph as it's being read
+struct BdfChar {
+    encoding: Option<char>,
+    bbx: BoundingBox,
+    // how far the pen moves afterwards, `DWIDTH`
+    advance: Option<usize>,
+    rows: Vec<Vec<u8>>,
+}
+
+/// Load a BDF (Glyph Bitmap Distribution Format) font, the text format X11
+/// bitmap fonts come in. Glyphs can be any size; they're all placed on their
+/// baseline in a cell the size of the font's bounding box. Unencoded glyphs
+/// are skipped. Characters it doesn't have get `DEFAULT_CHAR` if the font
+/// names one, or `?`, or the first glyph, in that order.
+pub fn parse(data: &[u8]) -> Result<Font, &'static str> {
+    let text = str::from_utf8(data).map_err(|_| "BDF font isn't text")?;
+    let mut lines = text.lines();
+    match lines.next() {
+        Some(line) if line.starts_with("STARTFONT") => {}
+        _ => return Err("not a BDF font"),
+    }
+
+    let mut font_bbx: Option<BoundingBox> = None;
+    let mut ascent: Option<isize> = None;
+    let mut descent: Option<isize> = None;
+    let mut default_char: Option<char> = None;
+    let mut chars: Vec<BdfChar> = Vec::new();
+    let mut current: Option<BdfChar> = None;
+    let mut in_bitmap = false;
+
+    for line in lines {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let (keyword, args) = match words.split_first() {
+            Some((keyword, args)) => (*keyword, args),
+            None => continue,
+        };
+
+        if in_bitmap {
+            let glyph = current.as_mut().ok_or("BITMAP outside of a glyph")?;
+            if keyword == "ENDCHAR" {
+                in_bitmap = false;
+                chars.push(current.take().expect("checked above"));
+                continue;
+            }
+            // slicing by byte below needs every character to be one byte
+            if !keyword.is_ascii() {
+                return Err("bad bitmap row in BDF font");
+            }
+            let row: Result<Vec<u8>, _> = (0..keyword.len() / 2)
+                .map(|i| u8::from_str_radix(&keyword[i * 2..i * 2 + 2], 16))
+                .collect();
+            glyph
+                .rows
+                .push(row.map_err(|_| "bad bitmap row in BDF font")?);
+            continue;
+        }
+
+        match keyword {
+            "FONTBOUNDINGBOX" => {
+                // single glyphs can be empty, but the cell they go in can't
+                let bbx = parse_bounding_box(args)?;
+                if bbx.0 == 0 || bbx.1 == 0 {
+                    return Err("empty FONTBOUNDINGBOX in BDF font");
+                }
+                font_bbx = Some(bbx);
+            }
+            "FONT_ASCENT" => ascent = parse_numbers(args)?.first().copied(),
+            "FONT_DESCENT" => descent = parse_numbers(args)?.first().copied(),
+            "DEFAULT_CHAR" => {
+                default_char = parse_numbers(args)?
+                    .first()
+                    .and_then(|n| char::from_u32(*n as u32));
+            }
+            "STARTCHAR" => {
+                current = Some(BdfChar {
+                    encoding: None,
+                    bbx: font_bbx.ok_or("glyph before FONTBOUNDINGBOX")?,
+                    advance: None,
+                    rows: Vec::new(),
+                });
+            }
+            "ENCODING" => {
+                let glyph = current.as_mut().ok_or("ENCODING outside of a glyph")?;
+                // -1 means unencoded
+                glyph.encoding = parse_numbers(args)?
+                    .first()
+                    .filter(|n| **n >= 0)
+                    .and_then(|n| char::from_u32(*n as u32));
+            }
+            "BBX" => {
+                let glyph = current.as_mut().ok_or("BBX outside of a glyph")?;
+                glyph.bbx = parse_bounding_box(args)?;
+            }
+            "DWIDTH" => {
+                let glyph = current.as_mut().ok_or("DWIDTH outside of a glyph")?;
+                glyph.advance = parse_numbers(args)?
+                    .first()
+                    .filter(|n| **n >= 0)
+                    .map(|n| *n as usize);
+            }
+            "BITMAP" => in_bitmap = true,
+            _ => {}
+        }
+    }
+
+    let (cell_width, bbx_height, bbx_x, bbx_y) = font_bbx.ok_or("no FONTBOUNDINGBOX")?;
+    // the baseline is `ascent` rows down from the top of the cell
+    let ascent = match ascent {
+        Some(value) => value,
+        None => (bbx_height as isize)
+            .checked_add(bbx_y)
+            .ok_or("bad FONTBOUNDINGBOX in BDF font")?,
+    };
+    let cell_height = match descent {
+        Some(value) => match ascent.checked_add(value) {
+            Some(height) if height > 0 => height as usize,
+            _ => return Err("bad FONT_ASCENT or FONT_DESCENT in BDF font"),
+        },
+        None => bbx_height,
+    };
+
+    let mut glyphs: Vec<MonoBitmap> = Vec::new();
+    let mut metrics: Vec<GlyphMetrics> = Vec::new();
+    let mut map: BTreeMap<char, usize> = BTreeMap::new();
+    for glyph in chars {
+        let c = match glyph.encoding {
+            Some(value) => value,
+            None => continue,
+        };
+        let (width, height, x_offset, y_offset) = glyph.bbx;
+        let bad_bbx = "bad BBX in BDF font";
+        let left = x_offset.checked_sub(bbx_x).ok_or(bad_bbx)?;
+        let top = y_offset
+            .checked_add(height as isize)
+            .and_then(|bottom| ascent.checked_sub(bottom))
+            .ok_or(bad_bbx)?;
+        let mut bitmap = MonoBitmap::empty(cell_height, cell_width);
+        for (y, row) in glyph.rows.iter().enumerate().take(height) {
+            // nothing past the end of the row is set, whatever BBX says
+            for x in 0..cmp::min(width, row.len() * 8) {
+                let set = row[x / 8] & (0x80 >> (x % 8)) != 0;
+                let cell = left
+                    .checked_add(x as isize)
+                    .zip(top.checked_add(y as isize));
+                if let (true, Some((cell_x, cell_y))) = (set, cell) {
+                    if cell_x >= 0
+                        && cell_y >= 0
+                        && (cell_x as usize) < cell_width
+                        && (cell_y as usize) < cell_height
+                    {
+                        bitmap.set(cell_x as usize, cell_y as usize);
+                    }
+                }
+            }
+        }
+        map.insert(c, glyphs.len());
+        glyphs.push(bitmap);
+        // the pen sits `bbx_x` px left of where the cell starts
+        metrics.push(GlyphMetrics {
+            bearing: cmp::max(-bbx_x, 0) as usize,
+            advance: glyph.advance.unwrap_or(cell_width),
+        });
+    }
+    if glyphs.len() == 0 {
+        return Err("BDF font has no glyphs");
+    }
+
+    let missing = default_char
+        .and_then(|c| map.get(&c))
+        .or_else(|| map.get(&'?'))
+        .copied()
+        .unwrap_or(0);
+    return Ok(Font::new(
+        (cell_width, cell_height),
+        glyphs,
+        metrics,
+        map,
+        missing,
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use no_std_compat::string::String;
+    use std::ptr;
+
+    /// A 4x4 font (3 rows above the baseline, 1 below) with `glyphs` in it
+    fn bdf(glyphs: &str) -> String {
+        let mut font = String::from("STARTFONT 2.1\nFONT test\nFONTBOUNDINGBOX 4 4 0 -1\n");
+        font += "STARTPROPERTIES 2\nFONT_ASCENT 3\nFONT_DESCENT 1\nENDPROPERTIES\n";
+        font += glyphs;
+        font += "ENDFONT\n";
+        return font;
+    }
+
+    /// One glyph, `rows` being the hex lines after BITMAP
+    fn glyph(encoding: isize, bbx: &str, rows: &[&str]) -> String {
+        let mut glyph = format!(
+            "STARTCHAR c{}\nENCODING {}\nBBX {}\nBITMAP\n",
+            encoding, encoding, bbx
+        );
+        for row in rows {
+            glyph += row;
+            glyph += "\n";
+        }
+        glyph += "ENDCHAR\n";
+        return glyph;
+    }
+
+    /// The glyph for `c` as rows of `#` and `.`
+    fn drawn(font: &Font, c: char) -> Vec<String> {
+        let glyph = font.glyph(c);
+        let (cols, rows) = glyph.dimensions();
+        return (0..rows)
+            .map(|y| {
+                (0..cols)
+                    .map(|x| if glyph.get(x, y) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+    }
+
+    #[test]
+    fn glyphs_sit_on_the_baseline() {
+        let glyphs = glyph(65, "2 2 1 0", &["C0", "40"]) + &glyph(103, "1 2 0 -1", &["80", "80"]);
+        let font = parse(bdf(&glyphs).as_bytes()).unwrap();
+        assert_eq!(font.cell(), (4, 4));
+        // on the baseline, one in from the left
+        assert_eq!(drawn(&font, 'A'), ["....", ".##.", "..#.", "...."]);
+        // hangs one row below it
+        assert_eq!(drawn(&font, 'g'), ["....", "....", "#...", "#..."]);
+    }
+
+    #[test]
+    fn bits_outside_the_cell_are_dropped() {
+        // 6 wide and starts left of the cell
+        let glyphs = glyph(65, "6 1 -1 0", &["FC"]);
+        let font = parse(bdf(&glyphs).as_bytes()).unwrap();
+        assert_eq!(drawn(&font, 'A'), ["....", "....", "####", "...."]);
+    }
+
+    #[test]
+    fn missing_characters_fall_back() {
+        let glyphs = glyph(65, "1 1 0 0", &["80"])
+            + &glyph(63, "1 1 1 0", &["80"])
+            + &glyph(66, "1 1 2 0", &["80"])
+            + &glyph(-1, "1 1 3 0", &["80"]);
+        let font = parse(bdf(&glyphs).as_bytes()).unwrap();
+        // `?`, since there's no DEFAULT_CHAR
+        assert!(ptr::eq(font.glyph('x'), font.glyph('?')));
+        // unencoded glyphs don't count
+        assert_eq!(font.glyphs.len(), 3);
+
+        let with_default = bdf(&glyphs).replace("ENDPROPERTIES", "DEFAULT_CHAR 66\nENDPROPERTIES");
+        let font = parse(with_default.as_bytes()).unwrap();
+        assert!(ptr::eq(font.glyph('x'), font.glyph('B')));
+
+        let font = parse(bdf(&glyph(66, "1 1 0 0", &["80"])).as_bytes()).unwrap();
+        assert!(ptr::eq(font.glyph('x'), font.glyph('B')));
+    }
+
+    #[test]
+    fn bad_fonts() {
+        let a = glyph(65, "1 1 0 0", &["80"]);
+        assert!(parse(bdf(&a).as_bytes()).is_ok());
+        assert!(parse(b"not a font").is_err());
+        assert!(parse(bdf("").as_bytes()).is_err());
+        // the cell can't be empty or negative
+        for bbx in ["0 4 0 -1", "4 -4 0 -1", "4 4 0", "4 4 x -1"].iter() {
+            let font = bdf(&a).replace(
+                "FONTBOUNDINGBOX 4 4 0 -1",
+                &format!("FONTBOUNDINGBOX {}", bbx),
+            );
+            assert!(parse(font.as_bytes()).is_err(), "FONTBOUNDINGBOX {}", bbx);
+        }
+        for bbx in ["-1 1 0 0", "1 1 0", "1 1 0 z"].iter() {
+            let font = bdf(&glyph(65, bbx, &["80"]));
+            assert!(parse(font.as_bytes()).is_err(), "BBX {}", bbx);
+        }
+        for row in ["8G", "é0"].iter() {
+            let font = bdf(&glyph(65, "1 1 0 0", &[row]));
+            assert!(parse(font.as_bytes()).is_err(), "bitmap row {}", row);
+        }
+        let font = bdf(&a).replace("FONT_DESCENT 1", "FONT_DESCENT -3");
+        assert!(parse(font.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn dwidth_is_the_advance() {
+        let with_dwidth = glyph(65, "1 1 0 0", &["80"]).replace("BBX", "DWIDTH 3 0\nBBX");
+        let glyphs = with_dwidth + &glyph(66, "1 1 0 0", &["80"]);
+        let font = parse(bdf(&glyphs).as_bytes()).unwrap();
+        assert_eq!(font.metrics('A').advance, 3);
+        // no DWIDTH, so a whole cell
+        assert_eq!(font.metrics('B').advance, 4);
+        assert_eq!(font.metrics('A').bearing, 0);
+
+        // the cell starts a px left of the pen
+        let shifted = bdf(&glyphs).replace("FONTBOUNDINGBOX 4 4 0 -1", "FONTBOUNDINGBOX 4 4 -1 -1");
+        let font = parse(shifted.as_bytes()).unwrap();
+        assert_eq!(font.metrics('A').bearing, 1);
+        assert_eq!(drawn(&font, 'A'), ["....", "....", ".#..", "...."]);
+    }
+}
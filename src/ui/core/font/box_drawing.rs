@@ -0,0 +1,273 @@
+use no_std_compat::cmp;
+
+use super::{Glyph, FONT_HEIGHT, FONT_WIDTH};
+
+/// Where lines through the middle of the cell are drawn
+const MIDDLE: (usize, usize) = (FONT_WIDTH / 2, FONT_HEIGHT / 2);
+
+/// Which lines leave the middle of the cell for each of U+2500 to U+257F, one
+/// hex digit each for (up, right, down, left): 0 nothing, 1 light, 2 heavy, 3
+/// double. Dashes, arcs and diagonals get touched up afterwards.
+const ARMS: [u16; 128] = [
+    0x0101, 0x0202, 0x1010, 0x2020, 0x0101, 0x0202, 0x1010, 0x2020, 0x0101, 0x0202, 0x1010, 0x2020,
+    0x0110, 0x0210, 0x0120, 0x0220, 0x0011, 0x0012, 0x0021, 0x0022, 0x1100, 0x1200, 0x2100, 0x2200,
+    0x1001, 0x1002, 0x2001, 0x2002, 0x1110, 0x1210, 0x2110, 0x1120, 0x2120, 0x2210, 0x1220, 0x2220,
+    0x1011, 0x1012, 0x2011, 0x1021, 0x2021, 0x2012, 0x1022, 0x2022, 0x0111, 0x0112, 0x0211, 0x0212,
+    0x0121, 0x0122, 0x0221, 0x0222, 0x1101, 0x1102, 0x1201, 0x1202, 0x2101, 0x2102, 0x2201, 0x2202,
+    0x1111, 0x1112, 0x1211, 0x1212, 0x2111, 0x1121, 0x2121, 0x2112, 0x2211, 0x1122, 0x1221, 0x2212,
+    0x1222, 0x2122, 0x2221, 0x2222, 0x0101, 0x0202, 0x1010, 0x2020, 0x0303, 0x3030, 0x0310, 0x0130,
+    0x0330, 0x0013, 0x0031, 0x0033, 0x1300, 0x3100, 0x3300, 0x1003, 0x3001, 0x3003, 0x1310, 0x3130,
+    0x3330, 0x1013, 0x3031, 0x3033, 0x0313, 0x0131, 0x0333, 0x1303, 0x3101, 0x3303, 0x1313, 0x3131,
+    0x3333, 0x0110, 0x0011, 0x1001, 0x1100, 0x0000, 0x0000, 0x0000, 0x0001, 0x1000, 0x0100, 0x0010,
+    0x0002, 0x2000, 0x0200, 0x0020, 0x0201, 0x1020, 0x0102, 0x2010,
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Weight {
+    Empty,
+    Light,
+    Heavy,
+    Double,
+}
+
+impl Weight {
+    fn from_digit(digit: u16) -> Weight {
+        return match digit {
+            1 => Weight::Light,
+            2 => Weight::Heavy,
+            3 => Weight::Double,
+            _ => Weight::Empty,
+        };
+    }
+
+    /// Which strokes across the middle a line is made of, in px either side
+    fn strokes(&self) -> &'static [isize] {
+        return match self {
+            Weight::Empty => &[],
+            Weight::Light => &[0],
+            Weight::Heavy => &[-1, 0, 1],
+            Weight::Double => &[-1, 1],
+        };
+    }
+
+    /// How far a line sticks out either side of the middle
+    fn half_width(&self) -> isize {
+        return match self {
+            Weight::Heavy | Weight::Double => 1,
+            _ => 0,
+        };
+    }
+}
+
+/// A cell being drawn, pixel by pixel
+struct Canvas(Glyph);
+
+impl Canvas {
+    fn set(&mut self, x: usize, y: usize, on: bool) {
+        let bit = 1 << (FONT_WIDTH - 1 - x);
+        if on {
+            self.0[y] |= bit;
+        } else {
+            self.0[y] &= !bit;
+        }
+    }
+
+    fn fill(&mut self, x: (usize, usize), y: (usize, usize)) {
+        for row in y.0..y.1 {
+            for col in x.0..x.1 {
+                self.set(col, row, true);
+            }
+        }
+    }
+
+    /// Fill every pixel `on(x, y)` says yes to
+    fn fill_shade(&mut self, on: impl Fn(usize, usize) -> bool) {
+        for y in 0..FONT_HEIGHT {
+            for x in 0..FONT_WIDTH {
+                if on(x, y) {
+                    self.set(x, y, true);
+                }
+            }
+        }
+    }
+
+    /// Clear every pixel `keep(x, y)` says no to
+    fn mask(&mut self, keep: impl Fn(usize, usize) -> bool) {
+        for y in 0..FONT_HEIGHT {
+            for x in 0..FONT_WIDTH {
+                if !keep(x, y) {
+                    self.set(x, y, false);
+                }
+            }
+        }
+    }
+}
+
+/// Rows for a box-drawing character (U+2500 to U+257F) or block element
+/// (U+2580 to U+259F). Both are drawn right out to the edges of the cell so
+/// neighbours join up. Anything else comes back blank.
+pub fn rows(c: char) -> Glyph {
+    let mut canvas = Canvas([0; FONT_HEIGHT]);
+    let code = c as usize;
+    match code {
+        0x2500..=0x257f => {
+            draw_lines(&mut canvas, ARMS[code - 0x2500]);
+            match code {
+                // dashed lines: (dashes, horizontal)
+                0x2504 | 0x2505 => dash(&mut canvas, 3, true),
+                0x2506 | 0x2507 => dash(&mut canvas, 3, false),
+                0x2508 | 0x2509 => dash(&mut canvas, 4, true),
+                0x250a | 0x250b => dash(&mut canvas, 4, false),
+                0x254c | 0x254d => dash(&mut canvas, 2, true),
+                0x254e | 0x254f => dash(&mut canvas, 2, false),
+                // arcs: a corner with the corner cut off
+                0x256d..=0x2570 => canvas.set(MIDDLE.0, MIDDLE.1, false),
+                0x2571 => diagonal(&mut canvas, false),
+                0x2572 => diagonal(&mut canvas, true),
+                0x2573 => {
+                    diagonal(&mut canvas, false);
+                    diagonal(&mut canvas, true);
+                }
+                _ => {}
+            }
+        }
+        0x2580..=0x259f => draw_block(&mut canvas, code),
+        _ => {}
+    }
+    return canvas.0;
+}
+
+/// Lines from the middle out to the edges, `arms` as in `ARMS`. Where double
+/// lines meet, each stroke stops at the stroke it runs into so corners and
+/// junctions come out like they're drawn with two pens.
+fn draw_lines(canvas: &mut Canvas, arms: u16) {
+    // up, right, down, left
+    let weights: [Weight; 4] = [
+        Weight::from_digit((arms >> 12) & 0xf),
+        Weight::from_digit((arms >> 8) & 0xf),
+        Weight::from_digit((arms >> 4) & 0xf),
+        Weight::from_digit(arms & 0xf),
+    ];
+    for arm in 0..4 {
+        let weight = weights[arm];
+        let vertical = arm % 2 == 0;
+        // the arms on either side of this one: the one strokes at -1 are
+        // closer to first
+        let (before, after) = if vertical {
+            (weights[3], weights[1])
+        } else {
+            (weights[0], weights[2])
+        };
+        let (middle, across_middle, length) = if vertical {
+            (MIDDLE.1, MIDDLE.0, FONT_HEIGHT)
+        } else {
+            (MIDDLE.0, MIDDLE.1, FONT_WIDTH)
+        };
+
+        for stroke in weight.strokes() {
+            // how far past the middle the stroke reaches, towards the far side
+            let reach = if weight == Weight::Double {
+                let (near, far) = if *stroke < 0 {
+                    (before, after)
+                } else {
+                    (after, before)
+                };
+                if near != Weight::Empty {
+                    -near.half_width()
+                } else {
+                    far.half_width()
+                }
+            } else {
+                cmp::max(before.half_width(), after.half_width())
+            };
+
+            // up and left run from the edge to the middle, the others from
+            // the middle to the edge
+            let (from, to) = if arm == 0 || arm == 3 {
+                (0, (middle as isize + reach) as usize)
+            } else {
+                ((middle as isize - reach) as usize, length - 1)
+            };
+            let across = (across_middle as isize + stroke) as usize;
+            for along in from..=to {
+                if vertical {
+                    canvas.set(across, along, true);
+                } else {
+                    canvas.set(along, across, true);
+                }
+            }
+        }
+    }
+}
+
+/// Break lines up into `dashes` dashes along the cell
+fn dash(canvas: &mut Canvas, dashes: usize, horizontal: bool) {
+    let length = if horizontal { FONT_WIDTH } else { FONT_HEIGHT };
+    let on = |along: usize| ((along * dashes * 2) / length) % 2 == 0;
+    canvas.mask(|x, y| if horizontal { on(x) } else { on(y) });
+}
+
+/// Corner to corner. `falling` goes from the top-left down to the
+/// bottom-right, otherwise from the top-right down to the bottom-left.
+fn diagonal(canvas: &mut Canvas, falling: bool) {
+    for y in 0..FONT_HEIGHT {
+        let x = (y * (FONT_WIDTH - 1)) / (FONT_HEIGHT - 1);
+        if falling {
+            canvas.set(x, y, true);
+        } else {
+            canvas.set(FONT_WIDTH - 1 - x, y, true);
+        }
+    }
+}
+
+/// `n` eighths of `length` px, rounded
+fn eighths(n: usize, length: usize) -> usize {
+    return ((length * n) + 4) / 8;
+}
+
+fn draw_block(canvas: &mut Canvas, code: usize) {
+    let (w, h) = (FONT_WIDTH, FONT_HEIGHT);
+    // where the cell splits in half. upper and lower halves (or left and
+    // right) always add up to the whole cell
+    let split = (eighths(4, w), h - eighths(4, h));
+    match code {
+        0x2580 => canvas.fill((0, w), (0, split.1)),
+        // lower one eighth up to the full block
+        0x2581..=0x2588 => canvas.fill((0, w), (h - eighths(code - 0x2580, h), h)),
+        // left seven eighths down to left one eighth
+        0x2589..=0x258f => canvas.fill((0, eighths(0x2590 - code, w)), (0, h)),
+        0x2590 => canvas.fill((split.0, w), (0, h)),
+        0x2591 => canvas.fill_shade(|x, y| x % 2 == 0 && y % 2 == 0),
+        0x2592 => canvas.fill_shade(|x, y| (x + y) % 2 == 0),
+        0x2593 => canvas.fill_shade(|x, y| x % 2 == 0 || y % 2 == 0),
+        0x2594 => canvas.fill((0, w), (0, eighths(1, h))),
+        0x2595 => canvas.fill((w - eighths(1, w), w), (0, h)),
+        0x2596..=0x259f => {
+            // quadrants: upper left, upper right, lower left, lower right
+            let quadrants: [bool; 4] = match code {
+                0x2596 => [false, false, true, false],
+                0x2597 => [false, false, false, true],
+                0x2598 => [true, false, false, false],
+                0x2599 => [true, false, true, true],
+                0x259a => [true, false, false, true],
+                0x259b => [true, true, true, false],
+                0x259c => [true, true, false, true],
+                0x259d => [false, true, false, false],
+                0x259e => [false, true, true, false],
+                _ => [false, true, true, true],
+            };
+            let spans = [
+                ((0, split.0), (0, split.1)),
+                ((split.0, w), (0, split.1)),
+                ((0, split.0), (split.1, h)),
+                ((split.0, w), (split.1, h)),
+            ];
+            for (quadrant, span) in quadrants.iter().zip(spans.iter()) {
+                if *quadrant {
+                    canvas.fill(span.0, span.1);
+                }
+            }
+        }
+        _ => {}
+    }
+}
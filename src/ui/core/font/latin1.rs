@@ -0,0 +1,762 @@
+use super::{
+    Glyph, A, C, E, FONT_WIDTH, HYPHEN, I, MISSING, N, O, SMALL_A, SMALL_C, SMALL_E, SMALL_I,
+    SMALL_N, SMALL_O, SMALL_U, SMALL_Y, SPACE, U, Y,
+};
+
+/// Marks that go on top of (or under) a letter
+#[derive(Clone, Copy, PartialEq)]
+enum Accent {
+    Grave,
+    Acute,
+    Circumflex,
+    Tilde,
+    Diaeresis,
+    Ring,
+    Cedilla,
+}
+
+impl Accent {
+    /// Columns to fill relative to the middle of the letter, one slice per row
+    /// from the top of the cell down (or from just under the baseline for
+    /// `Cedilla`)
+    fn marks(&self, capital: bool) -> &'static [&'static [isize]] {
+        return match self {
+            Accent::Grave => &[&[-1], &[0]],
+            Accent::Acute => &[&[1], &[0]],
+            Accent::Circumflex => &[&[0], &[-1, 1]],
+            Accent::Tilde => &[&[-1, 0, 2], &[-2, 1]],
+            Accent::Diaeresis if capital => &[&[-1, 1]],
+            Accent::Diaeresis => &[&[], &[-1, 1]],
+            // capitals close the ring off with their own top
+            Accent::Ring if capital => &[&[-1, 0, 1], &[-1, 1]],
+            Accent::Ring => &[&[0], &[-1, 1], &[0]],
+            Accent::Cedilla => &[&[0], &[-1, 0]],
+        };
+    }
+}
+
+/// Rows for a character in the Latin-1 Supplement (U+00A0 to U+00FF).
+/// Accented letters are put together from the plain ones.
+pub fn rows(c: char) -> Glyph {
+    let mut dotless_i = SMALL_I;
+    dotless_i[1] = 0;
+
+    return match c {
+        // no-break space
+        '\u{a0}' => SPACE,
+        '¡' => INVERTED_BANG,
+        '¢' => CENT,
+        '£' => POUND,
+        '¤' => CURRENCY,
+        '¥' => YEN,
+        '¦' => BROKEN_BAR,
+        '§' => SECTION,
+        '¨' => DIAERESIS,
+        '©' => COPYRIGHT,
+        'ª' => FEMININE,
+        '«' => L_GUILLEMET,
+        '¬' => NOT,
+        // soft hyphen
+        '\u{ad}' => HYPHEN,
+        '®' => REGISTERED,
+        '¯' => MACRON,
+        '°' => DEGREE,
+        '±' => PLUS_MINUS,
+        '²' => SUPERSCRIPT_TWO,
+        '³' => SUPERSCRIPT_THREE,
+        '´' => ACUTE,
+        'µ' => MICRO,
+        '¶' => PILCROW,
+        '·' => MIDDLE_DOT,
+        '¸' => CEDILLA,
+        '¹' => SUPERSCRIPT_ONE,
+        'º' => MASCULINE,
+        '»' => R_GUILLEMET,
+        '¼' => ONE_QUARTER,
+        '½' => ONE_HALF,
+        '¾' => THREE_QUARTERS,
+        '¿' => INVERTED_QUESTION,
+        'À' => accented(&A, Accent::Grave, true),
+        'Á' => accented(&A, Accent::Acute, true),
+        'Â' => accented(&A, Accent::Circumflex, true),
+        'Ã' => accented(&A, Accent::Tilde, true),
+        'Ä' => accented(&A, Accent::Diaeresis, true),
+        'Å' => accented(&A, Accent::Ring, true),
+        'Æ' => AE,
+        'Ç' => accented(&C, Accent::Cedilla, true),
+        'È' => accented(&E, Accent::Grave, true),
+        'É' => accented(&E, Accent::Acute, true),
+        'Ê' => accented(&E, Accent::Circumflex, true),
+        'Ë' => accented(&E, Accent::Diaeresis, true),
+        'Ì' => accented(&I, Accent::Grave, true),
+        'Í' => accented(&I, Accent::Acute, true),
+        'Î' => accented(&I, Accent::Circumflex, true),
+        'Ï' => accented(&I, Accent::Diaeresis, true),
+        'Ð' => ETH,
+        'Ñ' => accented(&N, Accent::Tilde, true),
+        'Ò' => accented(&O, Accent::Grave, true),
+        'Ó' => accented(&O, Accent::Acute, true),
+        'Ô' => accented(&O, Accent::Circumflex, true),
+        'Õ' => accented(&O, Accent::Tilde, true),
+        'Ö' => accented(&O, Accent::Diaeresis, true),
+        '×' => MULTIPLY,
+        'Ø' => O_STROKE,
+        'Ù' => accented(&U, Accent::Grave, true),
+        'Ú' => accented(&U, Accent::Acute, true),
+        'Û' => accented(&U, Accent::Circumflex, true),
+        'Ü' => accented(&U, Accent::Diaeresis, true),
+        'Ý' => accented(&Y, Accent::Acute, true),
+        'Þ' => THORN,
+        'ß' => SHARP_S,
+        'à' => accented(&SMALL_A, Accent::Grave, false),
+        'á' => accented(&SMALL_A, Accent::Acute, false),
+        'â' => accented(&SMALL_A, Accent::Circumflex, false),
+        'ã' => accented(&SMALL_A, Accent::Tilde, false),
+        'ä' => accented(&SMALL_A, Accent::Diaeresis, false),
+        'å' => accented(&SMALL_A, Accent::Ring, false),
+        'æ' => SMALL_AE,
+        'ç' => accented(&SMALL_C, Accent::Cedilla, false),
+        'è' => accented(&SMALL_E, Accent::Grave, false),
+        'é' => accented(&SMALL_E, Accent::Acute, false),
+        'ê' => accented(&SMALL_E, Accent::Circumflex, false),
+        'ë' => accented(&SMALL_E, Accent::Diaeresis, false),
+        'ì' => accented(&dotless_i, Accent::Grave, false),
+        'í' => accented(&dotless_i, Accent::Acute, false),
+        'î' => accented(&dotless_i, Accent::Circumflex, false),
+        'ï' => accented(&dotless_i, Accent::Diaeresis, false),
+        'ð' => SMALL_ETH,
+        'ñ' => accented(&SMALL_N, Accent::Tilde, false),
+        'ò' => accented(&SMALL_O, Accent::Grave, false),
+        'ó' => accented(&SMALL_O, Accent::Acute, false),
+        'ô' => accented(&SMALL_O, Accent::Circumflex, false),
+        'õ' => accented(&SMALL_O, Accent::Tilde, false),
+        'ö' => accented(&SMALL_O, Accent::Diaeresis, false),
+        '÷' => DIVIDE,
+        'ø' => SMALL_O_STROKE,
+        'ù' => accented(&SMALL_U, Accent::Grave, false),
+        'ú' => accented(&SMALL_U, Accent::Acute, false),
+        'û' => accented(&SMALL_U, Accent::Circumflex, false),
+        'ü' => accented(&SMALL_U, Accent::Diaeresis, false),
+        'ý' => accented(&SMALL_Y, Accent::Acute, false),
+        'þ' => SMALL_THORN,
+        'ÿ' => accented(&SMALL_Y, Accent::Diaeresis, false),
+        _ => MISSING,
+    };
+}
+
+/// `base` with `accent` added. Capitals get squashed down a row to make room
+/// since they already reach the top of the cell.
+fn accented(base: &Glyph, accent: Accent, capital: bool) -> Glyph {
+    let mut rows = *base;
+    let first_row = if accent == Accent::Cedilla {
+        8
+    } else {
+        if capital {
+            squash(&mut rows);
+        }
+        0
+    };
+    let middle = middle(&rows) as isize;
+    for (y, cols) in accent.marks(capital).iter().enumerate() {
+        for col in cols.iter() {
+            let x = (middle + col) as usize;
+            rows[first_row + y] |= 1 << (FONT_WIDTH - 1 - x);
+        }
+    }
+    return rows;
+}
+
+/// Drop one of the rows a capital repeats (there's always a straight stretch
+/// somewhere) and move everything above it down one, leaving the top two rows
+/// free.
+fn squash(rows: &mut Glyph) {
+    let repeat = (1..7).find(|y| rows[*y] == rows[y + 1]).unwrap_or(1);
+    for y in (1..=repeat).rev() {
+        rows[y] = rows[y - 1];
+    }
+}
+
+/// Column halfway between the leftmost and rightmost ink
+fn middle(rows: &Glyph) -> usize {
+    let ink = rows.iter().fold(0, |ink, row| ink | row);
+    // the leftmost column is the highest of the `FONT_WIDTH` bits
+    let left = FONT_WIDTH - 1 - (15 - ink.leading_zeros() as usize);
+    let right = FONT_WIDTH - 1 - ink.trailing_zeros() as usize;
+    return (left + right) / 2;
+}
+
+// drawn to match the ASCII glyphs
+
+const INVERTED_BANG: Glyph = [
+    0b000000000,
+    0b000110000,
+    0b000000000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+];
+
+const INVERTED_QUESTION: Glyph = [
+    0b000000000,
+    0b000011000,
+    0b000000000,
+    0b000011000,
+    0b001110000,
+    0b011000000,
+    0b011000000,
+    0b011001000,
+    0b001110000,
+    0b000000000,
+    0b000000000,
+];
+
+const CENT: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000010000,
+    0b001111100,
+    0b011010000,
+    0b011010000,
+    0b011010000,
+    0b001111100,
+    0b000010000,
+    0b000000000,
+    0b000000000,
+];
+
+const POUND: Glyph = [
+    0b000000000,
+    0b000111000,
+    0b001101100,
+    0b001100000,
+    0b011110000,
+    0b001100000,
+    0b001100000,
+    0b011111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const CURRENCY: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b010000010,
+    0b001111100,
+    0b001101100,
+    0b001101100,
+    0b001111100,
+    0b010000010,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const YEN: Glyph = [
+    0b000000000,
+    0b011000110,
+    0b001101100,
+    0b000111000,
+    0b011111110,
+    0b000010000,
+    0b011111110,
+    0b000010000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const BROKEN_BAR: Glyph = [
+    0b000000000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+];
+
+const SECTION: Glyph = [
+    0b000000000,
+    0b001111000,
+    0b011000000,
+    0b001110000,
+    0b011011000,
+    0b001110000,
+    0b000011000,
+    0b011110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const DIAERESIS: Glyph = [
+    0b000000000,
+    0b001101100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const COPYRIGHT: Glyph = [
+    0b000000000,
+    0b001111100,
+    0b010000010,
+    0b010111010,
+    0b010100010,
+    0b010111010,
+    0b010000010,
+    0b001111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const FEMININE: Glyph = [
+    0b000000000,
+    0b001110000,
+    0b000011000,
+    0b001111000,
+    0b011011000,
+    0b001111000,
+    0b000000000,
+    0b011111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const L_GUILLEMET: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000100100,
+    0b001001000,
+    0b010010000,
+    0b001001000,
+    0b000100100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const NOT: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011111100,
+    0b000001100,
+    0b000001100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const REGISTERED: Glyph = [
+    0b000000000,
+    0b001111100,
+    0b010110010,
+    0b010101010,
+    0b010110010,
+    0b010101010,
+    0b010000010,
+    0b001111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const MACRON: Glyph = [
+    0b000000000,
+    0b001111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const DEGREE: Glyph = [
+    0b000000000,
+    0b000111000,
+    0b001101100,
+    0b000111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const PLUS_MINUS: Glyph = [
+    0b000000000,
+    0b000110000,
+    0b000110000,
+    0b011111100,
+    0b000110000,
+    0b000110000,
+    0b000000000,
+    0b011111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SUPERSCRIPT_TWO: Glyph = [
+    0b000000000,
+    0b001110000,
+    0b000010000,
+    0b001110000,
+    0b001000000,
+    0b001110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SUPERSCRIPT_THREE: Glyph = [
+    0b000000000,
+    0b001110000,
+    0b000010000,
+    0b000110000,
+    0b000010000,
+    0b001110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const ACUTE: Glyph = [
+    0b000000000,
+    0b000011000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const MICRO: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b011111100,
+    0b011000000,
+    0b011000000,
+    0b000000000,
+];
+
+const PILCROW: Glyph = [
+    0b000000000,
+    0b001111110,
+    0b011111010,
+    0b011111010,
+    0b001111010,
+    0b000011010,
+    0b000011010,
+    0b000011010,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const MIDDLE_DOT: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000110000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const CEDILLA: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000010000,
+    0b000110000,
+    0b000000000,
+];
+
+const SUPERSCRIPT_ONE: Glyph = [
+    0b000000000,
+    0b000100000,
+    0b001100000,
+    0b000100000,
+    0b000100000,
+    0b001110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const MASCULINE: Glyph = [
+    0b000000000,
+    0b001110000,
+    0b011011000,
+    0b011011000,
+    0b001110000,
+    0b000000000,
+    0b011111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const R_GUILLEMET: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b010010000,
+    0b001001000,
+    0b000100100,
+    0b001001000,
+    0b010010000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const ONE_QUARTER: Glyph = [
+    0b000000000,
+    0b010000010,
+    0b110000100,
+    0b010001000,
+    0b010010000,
+    0b000101010,
+    0b001001010,
+    0b010001110,
+    0b100000010,
+    0b000000000,
+    0b000000000,
+];
+
+const ONE_HALF: Glyph = [
+    0b000000000,
+    0b010000010,
+    0b110000100,
+    0b010001000,
+    0b010010000,
+    0b000101100,
+    0b001000010,
+    0b010000100,
+    0b100001110,
+    0b000000000,
+    0b000000000,
+];
+
+const THREE_QUARTERS: Glyph = [
+    0b000000000,
+    0b110000010,
+    0b001000100,
+    0b010001000,
+    0b001010000,
+    0b110101010,
+    0b001001010,
+    0b010001110,
+    0b100000010,
+    0b000000000,
+    0b000000000,
+];
+
+const AE: Glyph = [
+    0b000000000,
+    0b001111110,
+    0b011011000,
+    0b011011000,
+    0b011111100,
+    0b011011000,
+    0b011011000,
+    0b011011110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const ETH: Glyph = [
+    0b000000000,
+    0b011111000,
+    0b011001100,
+    0b011001100,
+    0b111101100,
+    0b011001100,
+    0b011001100,
+    0b011111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const MULTIPLY: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011001100,
+    0b001111000,
+    0b000110000,
+    0b001111000,
+    0b011001100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const O_STROKE: Glyph = [
+    0b000000000,
+    0b000111010,
+    0b001101100,
+    0b001101100,
+    0b001111100,
+    0b001101100,
+    0b001101100,
+    0b010111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const THORN: Glyph = [
+    0b000000000,
+    0b011000000,
+    0b011111000,
+    0b011001100,
+    0b011001100,
+    0b011111000,
+    0b011000000,
+    0b011000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SHARP_S: Glyph = [
+    0b000000000,
+    0b001111000,
+    0b011001100,
+    0b011011000,
+    0b011011000,
+    0b011001100,
+    0b011001100,
+    0b011011000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_AE: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011101100,
+    0b000010010,
+    0b011111110,
+    0b100100000,
+    0b011011100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_ETH: Glyph = [
+    0b000000000,
+    0b001011000,
+    0b000110000,
+    0b001011000,
+    0b001111100,
+    0b011001100,
+    0b011001100,
+    0b001111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const DIVIDE: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000110000,
+    0b000000000,
+    0b011111100,
+    0b000000000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_O_STROKE: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b001111010,
+    0b011001100,
+    0b011011100,
+    0b011101100,
+    0b101111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_THORN: Glyph = [
+    0b000000000,
+    0b011000000,
+    0b011000000,
+    0b011111000,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b011111000,
+    0b011000000,
+    0b011000000,
+    0b000000000,
+];
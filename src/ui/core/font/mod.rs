@@ -0,0 +1,1690 @@
+pub const FONT_WIDTH: usize = 9;
+pub const FONT_HEIGHT: usize = 11;
+
+use no_std_compat::collections::BTreeMap;
+use no_std_compat::vec::Vec;
+
+use crate::ui::core::bitmap::MonoBitmap;
+
+pub mod bdf;
+mod box_drawing;
+mod latin1;
+pub mod psf2;
+
+/// What `Graphics` calls the font it always has, the one in this file
+pub const BUILTIN: &str = "builtin";
+
+/// First and last of the box-drawing characters and block elements
+const BOX_DRAWING: (char, char) = ('\u{2500}', '\u{259f}');
+
+/// First and last of the Latin-1 Supplement
+const LATIN_1: (char, char) = ('\u{a0}', '\u{ff}');
+
+/// Where a glyph sits when text is set proportionally rather than in cells.
+/// In px, before any scaling.
+#[derive(Clone, Copy)]
+pub struct GlyphMetrics {
+    /// How far into the glyph's cell the pen position is, i.e. how much of
+    /// the cell's left edge gets skipped
+    pub bearing: usize,
+    /// How far the pen moves on to the next character
+    pub advance: usize,
+}
+
+impl GlyphMetrics {
+    /// Metrics for fonts that don't come with their own: the visible part of
+    /// the glyph plus one px of spacing. Blank glyphs (space) get half a cell.
+    pub fn from_ink(glyph: &MonoBitmap) -> GlyphMetrics {
+        let (cols, rows) = glyph.dimensions();
+        let inked: Vec<usize> = (0..cols)
+            .filter(|x| (0..rows).any(|y| glyph.get(*x, y)))
+            .collect();
+        return match (inked.first(), inked.last()) {
+            (Some(left), Some(right)) => GlyphMetrics {
+                bearing: *left,
+                advance: right - left + 2,
+            },
+            _ => GlyphMetrics {
+                bearing: 0,
+                advance: cols / 2,
+            },
+        };
+    }
+
+    /// `from_ink()` for every glyph, except the ones box-drawing and block
+    /// characters use: those keep the whole cell so they still join up in
+    /// proportional text.
+    pub fn for_glyphs(glyphs: &[MonoBitmap], chars: &BTreeMap<char, usize>) -> Vec<GlyphMetrics> {
+        let mut metrics: Vec<GlyphMetrics> = glyphs.iter().map(GlyphMetrics::from_ink).collect();
+        for (_, idx) in chars.range(BOX_DRAWING.0..=BOX_DRAWING.1) {
+            if let Some(glyph) = glyphs.get(*idx) {
+                metrics[*idx] = GlyphMetrics {
+                    bearing: 0,
+                    advance: glyph.dimensions().0,
+                };
+            }
+        }
+        return metrics;
+    }
+}
+
+/// A bitmap font. Every glyph fills the same size cell, which is all
+/// monospace text needs to know. For proportional text each glyph also has
+/// `GlyphMetrics`.
+pub struct Font {
+    cell: (usize, usize),
+    glyphs: Vec<MonoBitmap>,
+    metrics: Vec<GlyphMetrics>,
+    // which glyph each character is
+    chars: BTreeMap<char, usize>,
+    // the glyph for characters the font doesn't have
+    missing: usize,
+}
+
+impl Font {
+    /// glyphs: one `MonoBitmap` per glyph, all `cell` (x, y) sized
+    /// metrics: one per glyph, for proportional text
+    /// chars: which of `glyphs` to draw for each character
+    /// missing: which of `glyphs` to draw for everything else
+    pub fn new(
+        cell: (usize, usize),
+        glyphs: Vec<MonoBitmap>,
+        metrics: Vec<GlyphMetrics>,
+        chars: BTreeMap<char, usize>,
+        missing: usize,
+    ) -> Font {
+        assert!(missing < glyphs.len(), "no glyph for missing characters");
+        assert!(metrics.len() == glyphs.len(), "every glyph needs metrics");
+        Font {
+            cell: cell,
+            glyphs: glyphs,
+            metrics: metrics,
+            chars: chars,
+            missing: missing,
+        }
+    }
+
+    /// The 9x11 font drawn in this file: printable ASCII, Latin-1,
+    /// box-drawing and block elements
+    pub fn builtin() -> Font {
+        let mut glyphs: Vec<MonoBitmap> = Vec::new();
+        let mut chars: BTreeMap<char, usize> = BTreeMap::new();
+        let ranges = [(' ', '~'), LATIN_1, BOX_DRAWING];
+        for c in ranges.iter().flat_map(|(first, last)| *first..=*last) {
+            chars.insert(c, glyphs.len());
+            glyphs.push(get_glyph(c));
+        }
+        chars.insert('\t', chars[&' ']);
+        glyphs.push(MonoBitmap::from_rows(FONT_WIDTH, &MISSING));
+        let missing = glyphs.len() - 1;
+        let metrics = GlyphMetrics::for_glyphs(&glyphs, &chars);
+        return Font::new((FONT_WIDTH, FONT_HEIGHT), glyphs, metrics, chars, missing);
+    }
+
+    /// (x, y) size of a character cell in px, before any scaling
+    pub fn cell(&self) -> (usize, usize) {
+        return self.cell;
+    }
+
+    /// Which glyph `c` is drawn with
+    fn index(&self, c: char) -> usize {
+        return self.chars.get(&c).copied().unwrap_or(self.missing);
+    }
+
+    /// The glyph for `c`, or the missing glyph if the font doesn't have one
+    pub fn glyph(&self, c: char) -> &MonoBitmap {
+        return &self.glyphs[self.index(c)];
+    }
+
+    /// Proportional metrics for `c`'s glyph
+    pub fn metrics(&self, c: char) -> GlyphMetrics {
+        return self.metrics[self.index(c)];
+    }
+}
+
+/// One row per `u16`, leftmost pixel in the highest of the `FONT_WIDTH` bits
+type Glyph = [u16; FONT_HEIGHT];
+
+/// Given a character, return a new `MonoBitmap` of its glyph in the built-in
+/// font. This allocates every time; `Graphics` goes through its `GlyphCache`
+/// instead.
+pub fn get_glyph(c: char) -> MonoBitmap {
+    let rows = if LATIN_1.0 <= c && c <= LATIN_1.1 {
+        latin1::rows(c)
+    } else if BOX_DRAWING.0 <= c && c <= BOX_DRAWING.1 {
+        box_drawing::rows(c)
+    } else {
+        *glyph_rows(c)
+    };
+    return MonoBitmap::from_rows(FONT_WIDTH, &rows);
+}
+
+/// Rows for ASCII `c`. Anything the font doesn't cover gets `MISSING`, a
+/// box, so it's obvious something's off instead of quietly looking like some
+/// other character.
+fn glyph_rows(c: char) -> &'static Glyph {
+    return match c {
+        'A' => &A,
+        'B' => &B,
+        'C' => &C,
+        'D' => &D,
+        'E' => &E,
+        'F' => &F,
+        'G' => &G,
+        'H' => &H,
+        'I' => &I,
+        'J' => &J,
+        'K' => &K,
+        'L' => &L,
+        'M' => &M,
+        'N' => &N,
+        'O' => &O,
+        'P' => &P,
+        'Q' => &Q,
+        'R' => &R,
+        'S' => &S,
+        'T' => &T,
+        'U' => &U,
+        'V' => &V,
+        'W' => &W,
+        'X' => &X,
+        'Y' => &Y,
+        'Z' => &Z,
+        'a' => &SMALL_A,
+        'b' => &SMALL_B,
+        'c' => &SMALL_C,
+        'd' => &SMALL_D,
+        'e' => &SMALL_E,
+        'f' => &SMALL_F,
+        'g' => &SMALL_G,
+        'h' => &SMALL_H,
+        'i' => &SMALL_I,
+        'j' => &SMALL_J,
+        'k' => &SMALL_K,
+        'l' => &SMALL_L,
+        'm' => &SMALL_M,
+        'n' => &SMALL_N,
+        'o' => &SMALL_O,
+        'p' => &SMALL_P,
+        'q' => &SMALL_Q,
+        'r' => &SMALL_R,
+        's' => &SMALL_S,
+        't' => &SMALL_T,
+        'u' => &SMALL_U,
+        'v' => &SMALL_V,
+        'w' => &SMALL_W,
+        'x' => &SMALL_X,
+        'y' => &SMALL_Y,
+        'z' => &SMALL_Z,
+        '1' => &ONE,
+        '2' => &TWO,
+        '3' => &THREE,
+        '4' => &FOUR,
+        '5' => &FIVE,
+        '6' => &SIX,
+        '7' => &SEVEN,
+        '8' => &EIGHT,
+        '9' => &NINE,
+        '0' => &ZERO,
+        '!' => &BANG,
+        '?' => &QUESTION,
+        '-' => &HYPHEN,
+        '_' => &UNDERSCORE,
+        ':' => &COLON,
+        ';' => &SEMICOLON,
+        // tabs are spaces now, the flame war can end
+        ' ' | '\t' => &SPACE,
+        '(' => &L_PAREN,
+        ')' => &R_PAREN,
+        '[' => &L_BRACKET,
+        ']' => &R_BRACKET,
+        '{' => &L_BRACE,
+        '}' => &R_BRACE,
+        '\'' => &APOSTROPHE,
+        '"' => &QUOTE,
+        '.' => &PERIOD,
+        ',' => &COMMA,
+        '#' => &HASH,
+        '$' => &DOLLAR,
+        '%' => &PERCENT,
+        '&' => &AMPERSAND,
+        '*' => &ASTERISK,
+        '+' => &PLUS,
+        '/' => &SLASH,
+        '\\' => &BACKSLASH,
+        '<' => &LESS_THAN,
+        '=' => &EQUALS,
+        '>' => &GREATER_THAN,
+        '@' => &AT,
+        '^' => &CARET,
+        '`' => &BACKTICK,
+        '|' => &PIPE,
+        '~' => &TILDE,
+        _ => &MISSING,
+    };
+}
+
+// most symbols from these links
+// https://www.google.com/url?sa=i&url=http%3A%2F%2Flegionfonts.com%2Ffonts%2Fberkelium-bitmap&psig=AOvVaw3BztSTcsP_NJKX3-2huFxr&ust=163374FONT_WIDTH74634FONT_WIDTH000&source=images&cd=vfe&ved=0CAsQjRxqFwoTCICfnJ7uufMCFQAAAAAdAAAAABAY
+// https://lh3.googleusercontent.com/proxy/eeQVpu6U1ULd27LXxJ8s-SNPBsGPw6nogrZfStRAZL0nr3oTPBt57Tn6DfxkBJCRVZNgMFONT_WIDTH_2E7QZ-Cp-FZTFONT_WIDTH0qCHAJfcd-Tt3yRKlZJwVL4xY4VQ3wVW_-4OImfFONT_WIDTHTsSVVe5TmQWU0Q
+// LegionFonts Berkelium bitmap font (free)
+//
+// i wrote them on a plane and the image was cut off so for some i improvised
+
+const A: Glyph = [
+    0b000000000,
+    0b000111000,
+    0b001101100,
+    0b011000110,
+    0b011000110,
+    0b011111110,
+    0b011000110,
+    0b011000110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const B: Glyph = [
+    0b000000000,
+    0b011111000,
+    0b011001100,
+    0b011001100,
+    0b011111000,
+    0b011001100,
+    0b011001100,
+    0b011111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const C: Glyph = [
+    0b000000000,
+    0b001111000,
+    0b011001100,
+    0b011000000,
+    0b011000000,
+    0b011000000,
+    0b011000100,
+    0b001111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const D: Glyph = [
+    0b000000000,
+    0b011111000,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b011111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const E: Glyph = [
+    0b000000000,
+    0b001111100,
+    0b001100000,
+    0b001100000,
+    0b001111000,
+    0b001100000,
+    0b001100000,
+    0b001111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const F: Glyph = [
+    0b000000000,
+    0b001111100,
+    0b001100000,
+    0b001100000,
+    0b001111000,
+    0b001100000,
+    0b001100000,
+    0b001100000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const G: Glyph = [
+    0b000000000,
+    0b001111000,
+    0b011001100,
+    0b011000000,
+    0b011000000,
+    0b011011100,
+    0b011001100,
+    0b001111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const H: Glyph = [
+    0b000000000,
+    0b011000110,
+    0b011000110,
+    0b011000110,
+    0b011111110,
+    0b011000110,
+    0b011000110,
+    0b011000110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const I: Glyph = [
+    0b000000000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const J: Glyph = [
+    0b000000000,
+    0b000011000,
+    0b000011000,
+    0b000011000,
+    0b000011000,
+    0b000011000,
+    0b000011000,
+    0b001110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const K: Glyph = [
+    0b000000000,
+    0b011000110,
+    0b011001100,
+    0b011011000,
+    0b011110000,
+    0b011011000,
+    0b011001100,
+    0b011000110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const L: Glyph = [
+    0b000000000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const M: Glyph = [
+    0b000000000,
+    0b011000110,
+    0b011101110,
+    0b011111110,
+    0b011010110,
+    0b011000110,
+    0b011000110,
+    0b011000110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const N: Glyph = [
+    0b000000000,
+    0b011000110,
+    0b011100110,
+    0b011110110,
+    0b011011110,
+    0b011001110,
+    0b011000110,
+    0b011000110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const O: Glyph = [
+    0b000000000,
+    0b000111000,
+    0b001101100,
+    0b001101100,
+    0b001101100,
+    0b001101100,
+    0b001101100,
+    0b000111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const P: Glyph = [
+    0b000000000,
+    0b001111000,
+    0b001101100,
+    0b001101100,
+    0b001111000,
+    0b001100000,
+    0b001100000,
+    0b001100000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const Q: Glyph = [
+    0b000000000,
+    0b001111000,
+    0b001001100,
+    0b011001100,
+    0b011001100,
+    0b011011100,
+    0b011001000,
+    0b001111110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const R: Glyph = [
+    0b000000000,
+    0b001111000,
+    0b001101100,
+    0b001101100,
+    0b001101000,
+    0b001110000,
+    0b001101000,
+    0b001101100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const S: Glyph = [
+    0b000000000,
+    0b000111000,
+    0b001100100,
+    0b001100000,
+    0b000111000,
+    0b000001100,
+    0b001001100,
+    0b000111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const T: Glyph = [
+    0b000000000,
+    0b001111100,
+    0b000010000,
+    0b000010000,
+    0b000010000,
+    0b000010000,
+    0b000010000,
+    0b000010000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const U: Glyph = [
+    0b000000000,
+    0b001101100,
+    0b001101100,
+    0b001101100,
+    0b001101100,
+    0b001101100,
+    0b001101100,
+    0b000111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const V: Glyph = [
+    0b000000000,
+    0b001101100,
+    0b001101100,
+    0b001101100,
+    0b001101100,
+    0b000101000,
+    0b000101000,
+    0b000010000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const W: Glyph = [
+    0b000000000,
+    0b011000110,
+    0b011000110,
+    0b011000110,
+    0b011010110,
+    0b011111110,
+    0b011101110,
+    0b001000100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const X: Glyph = [
+    0b000000000,
+    0b011000110,
+    0b001101100,
+    0b000111000,
+    0b000010000,
+    0b000111000,
+    0b001101100,
+    0b011000110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const Y: Glyph = [
+    0b000000000,
+    0b001101100,
+    0b001101100,
+    0b001101100,
+    0b000010000,
+    0b000010000,
+    0b000010000,
+    0b000010000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const Z: Glyph = [
+    0b000000000,
+    0b001111100,
+    0b000001100,
+    0b000011000,
+    0b000110000,
+    0b001100000,
+    0b001100000,
+    0b001111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const ONE: Glyph = [
+    0b000000000,
+    0b000011000,
+    0b000111000,
+    0b000111000,
+    0b000011000,
+    0b000011000,
+    0b000011000,
+    0b000011000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const TWO: Glyph = [
+    0b000000000,
+    0b000111000,
+    0b001101100,
+    0b000001100,
+    0b000001100,
+    0b000011000,
+    0b001110000,
+    0b001111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const THREE: Glyph = [
+    0b000000000,
+    0b000111000,
+    0b001101100,
+    0b000001100,
+    0b000011000,
+    0b000001100,
+    0b001101100,
+    0b000111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const FOUR: Glyph = [
+    0b000000000,
+    0b001101100,
+    0b001101100,
+    0b001101100,
+    0b001111100,
+    0b000001100,
+    0b000001100,
+    0b000001100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const FIVE: Glyph = [
+    0b000000000,
+    0b001111100,
+    0b001100000,
+    0b001111000,
+    0b000001100,
+    0b000001100,
+    0b001001100,
+    0b000111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SIX: Glyph = [
+    0b000000000,
+    0b000011100,
+    0b000110000,
+    0b001100000,
+    0b001111100,
+    0b001100110,
+    0b001100110,
+    0b000111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SEVEN: Glyph = [
+    0b000000000,
+    0b001111100,
+    0b000001100,
+    0b000011000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const EIGHT: Glyph = [
+    0b000000000,
+    0b001111000,
+    0b011001100,
+    0b011001100,
+    0b001111000,
+    0b011001100,
+    0b011001100,
+    0b001111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const NINE: Glyph = [
+    0b000000000,
+    0b001111000,
+    0b011001100,
+    0b011001100,
+    0b001111100,
+    0b000001100,
+    0b000011000,
+    0b001110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const ZERO: Glyph = [
+    0b000000000,
+    0b001111000,
+    0b011001100,
+    0b011001100,
+    0b011011100,
+    0b011101100,
+    0b011001100,
+    0b001111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const QUESTION: Glyph = [
+    0b000000000,
+    0b000111000,
+    0b001001100,
+    0b000001100,
+    0b000001100,
+    0b000111000,
+    0b000000000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const BANG: Glyph = [
+    0b000000000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000000000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+];
+
+const L_PAREN: Glyph = [
+    0b000000000,
+    0b000011100,
+    0b000110000,
+    0b001100000,
+    0b001100000,
+    0b001100000,
+    0b000110000,
+    0b000011100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const R_PAREN: Glyph = [
+    0b000000000,
+    0b001110000,
+    0b000011000,
+    0b000001100,
+    0b000001100,
+    0b000001100,
+    0b000011000,
+    0b001110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const L_BRACKET: Glyph = [
+    0b000000000,
+    0b000111000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const R_BRACKET: Glyph = [
+    0b000000000,
+    0b000111000,
+    0b000011000,
+    0b000011000,
+    0b000011000,
+    0b000011000,
+    0b000011000,
+    0b000111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const L_BRACE: Glyph = [
+    0b000000000,
+    0b000011000,
+    0b000110000,
+    0b000010000,
+    0b000011000,
+    0b000010000,
+    0b000110000,
+    0b000011000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const R_BRACE: Glyph = [
+    0b000000000,
+    0b000110000,
+    0b000011000,
+    0b000010000,
+    0b000110000,
+    0b000010000,
+    0b000011000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const COLON: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SEMICOLON: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000110000,
+    0b001110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const HYPHEN: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const UNDERSCORE: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011111110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SPACE: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const APOSTROPHE: Glyph = [
+    0b000000000,
+    0b000110000,
+    0b000110000,
+    0b000100000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const QUOTE: Glyph = [
+    0b000000000,
+    0b001101100,
+    0b001101100,
+    0b001001000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const PERIOD: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000110000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const COMMA: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000110000,
+    0b000110000,
+    0b000100000,
+    0b000000000,
+    0b000000000,
+];
+
+// lowercase, symbols and the missing glyph box are drawn to match the above
+
+const SMALL_A: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b001111000,
+    0b000001100,
+    0b001111100,
+    0b011001100,
+    0b001110110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_B: Glyph = [
+    0b000000000,
+    0b011000000,
+    0b011000000,
+    0b011111000,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b011111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_C: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b001111000,
+    0b011001100,
+    0b011000000,
+    0b011001100,
+    0b001111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_D: Glyph = [
+    0b000000000,
+    0b000001100,
+    0b000001100,
+    0b001111100,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b001111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_E: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b001111000,
+    0b011001100,
+    0b011111100,
+    0b011000000,
+    0b001111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_F: Glyph = [
+    0b000000000,
+    0b000111000,
+    0b001100000,
+    0b011111000,
+    0b001100000,
+    0b001100000,
+    0b001100000,
+    0b001100000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_G: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b001111100,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b001111100,
+    0b000001100,
+    0b001111000,
+    0b000000000,
+];
+
+const SMALL_H: Glyph = [
+    0b000000000,
+    0b011000000,
+    0b011000000,
+    0b011111000,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_I: Glyph = [
+    0b000000000,
+    0b000110000,
+    0b000000000,
+    0b001110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b001111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_J: Glyph = [
+    0b000000000,
+    0b000011000,
+    0b000000000,
+    0b000111000,
+    0b000011000,
+    0b000011000,
+    0b000011000,
+    0b000011000,
+    0b011011000,
+    0b001110000,
+    0b000000000,
+];
+
+const SMALL_K: Glyph = [
+    0b000000000,
+    0b011000000,
+    0b011000000,
+    0b011001100,
+    0b011011000,
+    0b011110000,
+    0b011011000,
+    0b011001100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_L: Glyph = [
+    0b000000000,
+    0b001110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b001111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_M: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011011000,
+    0b011111110,
+    0b011010110,
+    0b011010110,
+    0b011000110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_N: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011111000,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_O: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b001111000,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b001111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_P: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011111000,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b011111000,
+    0b011000000,
+    0b011000000,
+    0b000000000,
+];
+
+const SMALL_Q: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b001111100,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b001111100,
+    0b000001100,
+    0b000001100,
+    0b000000000,
+];
+
+const SMALL_R: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011011100,
+    0b011100000,
+    0b011000000,
+    0b011000000,
+    0b011000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_S: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b001111100,
+    0b011000000,
+    0b001111000,
+    0b000001100,
+    0b011111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_T: Glyph = [
+    0b000000000,
+    0b001100000,
+    0b001100000,
+    0b011111000,
+    0b001100000,
+    0b001100000,
+    0b001101100,
+    0b000111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_U: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b001110110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_V: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b001111000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_W: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011000110,
+    0b011010110,
+    0b011010110,
+    0b011111110,
+    0b001101100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_X: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011001100,
+    0b001111000,
+    0b000110000,
+    0b001111000,
+    0b011001100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SMALL_Y: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b011001100,
+    0b001111100,
+    0b000001100,
+    0b001111000,
+    0b000000000,
+];
+
+const SMALL_Z: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011111100,
+    0b000011000,
+    0b000110000,
+    0b001100000,
+    0b011111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const HASH: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b001101100,
+    0b011111110,
+    0b001101100,
+    0b001101100,
+    0b011111110,
+    0b001101100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const DOLLAR: Glyph = [
+    0b000000000,
+    0b000110000,
+    0b001111100,
+    0b011000000,
+    0b001111000,
+    0b000001100,
+    0b011111000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const PERCENT: Glyph = [
+    0b000000000,
+    0b011000110,
+    0b011001100,
+    0b000001100,
+    0b000011000,
+    0b000110000,
+    0b001100110,
+    0b011000110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const AMPERSAND: Glyph = [
+    0b000000000,
+    0b001110000,
+    0b011011000,
+    0b001110000,
+    0b011101010,
+    0b011011100,
+    0b011001100,
+    0b001110110,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const ASTERISK: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b001010100,
+    0b000111000,
+    0b011111110,
+    0b000111000,
+    0b001010100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const PLUS: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000110000,
+    0b000110000,
+    0b011111100,
+    0b000110000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const SLASH: Glyph = [
+    0b000000000,
+    0b000000110,
+    0b000001100,
+    0b000011000,
+    0b000110000,
+    0b001100000,
+    0b011000000,
+    0b110000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const BACKSLASH: Glyph = [
+    0b000000000,
+    0b011000000,
+    0b001100000,
+    0b000110000,
+    0b000011000,
+    0b000001100,
+    0b000000110,
+    0b000000011,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const LESS_THAN: Glyph = [
+    0b000000000,
+    0b000001100,
+    0b000011000,
+    0b000110000,
+    0b001100000,
+    0b000110000,
+    0b000011000,
+    0b000001100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const GREATER_THAN: Glyph = [
+    0b000000000,
+    0b011000000,
+    0b001100000,
+    0b000110000,
+    0b000011000,
+    0b000110000,
+    0b001100000,
+    0b011000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const EQUALS: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b011111100,
+    0b000000000,
+    0b011111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const AT: Glyph = [
+    0b000000000,
+    0b001111000,
+    0b011001100,
+    0b011011100,
+    0b011010100,
+    0b011011100,
+    0b011000000,
+    0b001111000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const CARET: Glyph = [
+    0b000000000,
+    0b000110000,
+    0b001111000,
+    0b011001100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const BACKTICK: Glyph = [
+    0b000000000,
+    0b001100000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const PIPE: Glyph = [
+    0b000000000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000110000,
+    0b000000000,
+    0b000000000,
+];
+
+const TILDE: Glyph = [
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b001110110,
+    0b011011100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+const MISSING: Glyph = [
+    0b000000000,
+    0b011111100,
+    0b010000100,
+    0b010000100,
+    0b010000100,
+    0b010000100,
+    0b010000100,
+    0b011111100,
+    0b000000000,
+    0b000000000,
+    0b000000000,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lowercase_letters_have_their_own_glyphs() {
+        for c in 'a'..='z' {
+            let upper = c.to_ascii_uppercase();
+            assert!(
+                glyph_rows(c) != glyph_rows(upper),
+                "{} looks like {}",
+                c,
+                upper
+            );
+        }
+    }
+
+    #[test]
+    fn printable_ascii_is_all_there() {
+        for c in (0x20u8..0x7f).map(char::from) {
+            assert!(*glyph_rows(c) != MISSING, "{:?} is missing", c);
+        }
+    }
+
+    #[test]
+    fn unmapped_chars_draw_missing() {
+        for c in ['\u{1}', '\u{7f}', '\u{4e2d}'] {
+            assert!(*glyph_rows(c) == MISSING, "{:?} isn't missing", c);
+        }
+        let missing = get_glyph('\u{4e2d}');
+        assert_eq!(missing.dimensions(), (FONT_WIDTH, FONT_HEIGHT));
+        assert!(missing.get(1, 1) && !missing.get(2, 2));
+    }
+
+    #[test]
+    fn metrics_come_from_the_ink() {
+        let glyph = MonoBitmap::from_rows(6, &[0b001100, 0b000110]);
+        let metrics = GlyphMetrics::from_ink(&glyph);
+        // columns 2 to 4 are inked, plus a px of spacing
+        assert_eq!((metrics.bearing, metrics.advance), (2, 4));
+        let blank = GlyphMetrics::from_ink(&MonoBitmap::from_rows(6, &[0, 0]));
+        assert_eq!((blank.bearing, blank.advance), (0, 3));
+
+        let builtin = Font::builtin();
+        assert!(builtin.metrics('i').advance < builtin.metrics('M').advance);
+        assert!(builtin.metrics('M').advance <= FONT_WIDTH + 1);
+    }
+
+    #[test]
+    fn builtin_covers_latin_1_and_box_drawing() {
+        let builtin = Font::builtin();
+        assert!(latin1::rows('\u{a0}') == *glyph_rows(' '));
+        assert!(latin1::rows('é') != *glyph_rows('e'));
+        for c in LATIN_1.0..=LATIN_1.1 {
+            assert!(builtin.index(c) != builtin.missing, "{:?} is missing", c);
+            assert!(latin1::rows(c) != MISSING, "{:?} draws missing", c);
+        }
+        for c in BOX_DRAWING.0..=BOX_DRAWING.1 {
+            assert!(builtin.index(c) != builtin.missing, "{:?} is missing", c);
+            assert!(box_drawing::rows(c) != [0; FONT_HEIGHT], "{:?} is blank", c);
+        }
+
+        // lines run right to the edge of the cell so they join up
+        let horizontal = get_glyph('─');
+        let vertical = get_glyph('│');
+        assert!(horizontal.get(0, FONT_HEIGHT / 2));
+        assert!(horizontal.get(FONT_WIDTH - 1, FONT_HEIGHT / 2));
+        assert!(vertical.get(FONT_WIDTH / 2, 0));
+        assert!(vertical.get(FONT_WIDTH / 2, FONT_HEIGHT - 1));
+    }
+}
@@ -0,0 +1,220 @@
+use no_std_compat::collections::BTreeMap;
+use no_std_compat::prelude::v1::Box;
+use no_std_compat::str;
+use no_std_compat::vec::Vec;
+
+use crate::bitmap::MonoBitmap;
+use crate::ui::core::font::{Font, GlyphMetrics};
+
+const MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+const HEADER_SIZE: usize = 32;
+/// Set in the header's flags if a Unicode table follows the glyphs
+const HAS_UNICODE_TABLE: u32 = 1;
+/// In the Unicode table: what follows are multi-codepoint sequences (which
+/// we don't draw), up to the next `END_OF_GLYPH`
+const START_OF_SEQUENCE: u8 = 0xfe;
+const END_OF_GLYPH: u8 = 0xff;
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    return u32::from_le_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+    ]);
+}
+
+/// Load a PC Screen Font v2, the format of the Linux console fonts
+/// (`/usr/share/consolefonts`, un-gzipped), e.g. from `include_bytes!()` or
+/// `Application::read_file()`. Fonts without a Unicode table map glyph N to
+/// character N. Characters it doesn't have get the replacement character, or
+/// `?`, or whatever glyph 0 is, in that order. Proportional spacing is
+/// worked out from what's drawn in each glyph.
+pub fn parse(data: &[u8]) -> Result<Font, &'static str> {
+    if data.len() < HEADER_SIZE || data[0..4] != MAGIC {
+        return Err("not a PSF2 font");
+    }
+    let header_size = read_u32(data, 8) as usize;
+    let flags = read_u32(data, 12);
+    let count = read_u32(data, 16) as usize;
+    let bytes_per_glyph = read_u32(data, 20) as usize;
+    let height = read_u32(data, 24) as usize;
+    let width = read_u32(data, 28) as usize;
+    if header_size < HEADER_SIZE {
+        return Err("PSF2 header is too short");
+    }
+    if count == 0 {
+        return Err("PSF2 font has no glyphs");
+    }
+    let row_bytes = (width + 7) / 8;
+    if width == 0 || height == 0 || height.checked_mul(row_bytes) != Some(bytes_per_glyph) {
+        return Err("PSF2 glyph size doesn't add up");
+    }
+    let glyphs_end = count
+        .checked_mul(bytes_per_glyph)
+        .and_then(|size| size.checked_add(header_size))
+        .ok_or("PSF2 font is too big")?;
+    let bits = data
+        .get(header_size..glyphs_end)
+        .ok_or("truncated PSF2 font")?;
+
+    // PSF2 rows are packed exactly like `MonoBitmap`'s
+    let glyphs: Vec<MonoBitmap> = bits
+        .chunks(bytes_per_glyph)
+        .map(|glyph| MonoBitmap::new(height, width, Box::from(glyph)))
+        .collect();
+
+    let mut chars: BTreeMap<char, usize> = BTreeMap::new();
+    if flags & HAS_UNICODE_TABLE != 0 {
+        // one entry per glyph: UTF-8 characters, then optionally some
+        // sequences, then END_OF_GLYPH
+        let mut table = &data[glyphs_end..];
+        for glyph in 0..count {
+            let end = table
+                .iter()
+                .position(|b| *b == END_OF_GLYPH)
+                .ok_or("truncated PSF2 Unicode table")?;
+            let entry = &table[..end];
+            let singles = match entry.iter().position(|b| *b == START_OF_SEQUENCE) {
+                Some(value) => &entry[..value],
+                None => entry,
+            };
+            let singles = str::from_utf8(singles).map_err(|_| "bad PSF2 Unicode table")?;
+            for c in singles.chars() {
+                chars.entry(c).or_insert(glyph);
+            }
+            table = &table[end + 1..];
+        }
+    } else {
+        for glyph in 0..count {
+            if let Some(c) = char::from_u32(glyph as u32) {
+                chars.insert(c, glyph);
+            }
+        }
+    }
+
+    let missing = chars
+        .get(&'\u{fffd}')
+        .or_else(|| chars.get(&'?'))
+        .copied()
+        .unwrap_or(0);
+    // console fonts are drawn for cells and don't say anything else
+    let metrics = GlyphMetrics::for_glyphs(&glyphs, &chars);
+    return Ok(Font::new((width, height), glyphs, metrics, chars, missing));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    /// A PSF2 font with `count` 4x2 glyphs, glyph N's top row being N, and
+    /// `table` after them
+    fn psf2(count: u32, flags: u32, table: &[u8]) -> Vec<u8> {
+        let mut data = MAGIC.to_vec();
+        for field in [0, HEADER_SIZE as u32, flags, count, 2, 2, 4].iter() {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        for glyph in 0..count {
+            data.extend_from_slice(&[(glyph as u8) << 4, 0]);
+        }
+        data.extend_from_slice(table);
+        return data;
+    }
+
+    /// Which glyph gets drawn for `c`
+    fn glyph_for(font: &Font, c: char) -> usize {
+        let glyph = font.glyph(c);
+        return font.glyphs.iter().position(|g| ptr::eq(g, glyph)).unwrap();
+    }
+
+    #[test]
+    fn glyph_n_is_char_n_without_a_table() {
+        let font = parse(&psf2(3, 0, &[])).unwrap();
+        assert_eq!(glyph_for(&font, '\u{1}'), 1);
+        assert_eq!(glyph_for(&font, '\u{2}'), 2);
+        assert_eq!(font.glyph('\u{2}').dimensions(), (4, 2));
+        // top row is 0b0010
+        assert!(font.glyph('\u{2}').get(2, 0) && !font.glyph('\u{2}').get(3, 0));
+        // no replacement character and no `?`
+        assert_eq!(glyph_for(&font, 'x'), 0);
+    }
+
+    #[test]
+    fn unicode_table() {
+        let mut table = Vec::new();
+        table.extend_from_slice(b"?\xff");
+        table.extend_from_slice(b"Aa\xff");
+        table.extend_from_slice("é".as_bytes());
+        // `e` followed by a combining accent is another way to write it
+        table.push(START_OF_SEQUENCE);
+        table.extend_from_slice("e\u{301}".as_bytes());
+        table.push(END_OF_GLYPH);
+        let font = parse(&psf2(3, HAS_UNICODE_TABLE, &table)).unwrap();
+
+        assert_eq!(glyph_for(&font, 'A'), 1);
+        assert_eq!(glyph_for(&font, 'a'), 1);
+        assert_eq!(glyph_for(&font, 'é'), 2);
+        // sequences aren't single characters
+        assert!(!font.chars.contains_key(&'e'));
+        assert!(!font.chars.contains_key(&'\u{301}'));
+        // and glyph numbers don't count as characters when there's a table
+        assert!(!font.chars.contains_key(&'\u{1}'));
+        assert_eq!(glyph_for(&font, 'e'), 0);
+    }
+
+    #[test]
+    fn missing_characters_fall_back() {
+        // the replacement character beats `?`
+        let mut table = b"?\xff".to_vec();
+        table.extend_from_slice("\u{fffd}".as_bytes());
+        table.push(END_OF_GLYPH);
+        let font = parse(&psf2(2, HAS_UNICODE_TABLE, &table)).unwrap();
+        assert_eq!(glyph_for(&font, 'x'), 1);
+
+        let font = parse(&psf2(2, HAS_UNICODE_TABLE, b"A\xff?\xff")).unwrap();
+        assert_eq!(glyph_for(&font, 'x'), 1);
+
+        let font = parse(&psf2(2, HAS_UNICODE_TABLE, b"A\xffB\xff")).unwrap();
+        assert_eq!(glyph_for(&font, 'x'), 0);
+    }
+
+    #[test]
+    fn truncated() {
+        let data = psf2(3, 0, &[]);
+        assert!(parse(&data[..data.len() - 1]).is_err());
+        assert!(parse(&data[..HEADER_SIZE - 1]).is_err());
+        // glyph 2 has no END_OF_GLYPH
+        assert!(parse(&psf2(3, HAS_UNICODE_TABLE, b"A\xffB\xffC")).is_err());
+        // half a UTF-8 character
+        assert!(parse(&psf2(3, HAS_UNICODE_TABLE, b"A\xff\xc3\xff\xff")).is_err());
+    }
+
+    #[test]
+    fn bad_headers() {
+        let good = psf2(1, 0, &[]);
+        assert!(parse(&good).is_ok());
+        let with = |offset: usize, value: u32| -> Vec<u8> {
+            let mut data = good.clone();
+            data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+            return data;
+        };
+        // magic
+        assert!(parse(&with(0, 0)).is_err());
+        // header size shorter than the header
+        assert!(parse(&with(8, 16)).is_err());
+        // no glyphs
+        assert!(parse(&with(16, 0)).is_err());
+        // bytes per glyph doesn't match width and height
+        assert!(parse(&with(20, 3)).is_err());
+        assert!(parse(&with(24, 0)).is_err());
+        assert!(parse(&with(28, 0)).is_err());
+        // sizes so big they'd wrap around if multiplied carelessly
+        let mut huge = with(16, u32::MAX);
+        huge[20..24].copy_from_slice(&u32::MAX.to_le_bytes());
+        huge[24..28].copy_from_slice(&u32::MAX.to_le_bytes());
+        huge[28..32].copy_from_slice(&8u32.to_le_bytes());
+        huge[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(parse(&huge).is_err());
+    }
+}
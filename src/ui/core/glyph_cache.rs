@@ -0,0 +1,255 @@
+use no_std_compat::collections::BTreeMap;
+
+use crate::bitmap::{AlphaBitmap, MonoBitmap};
+use crate::graphics::Color;
+use crate::ui::core::font::Font;
+use crate::ui::core::rect::Rect;
+use crate::ui::core::render_target::{PixelEncoder, RenderTarget};
+use crate::ui::core::scale::Scale;
+
+/// Everything that makes one rendered glyph different from another. Glyphs
+/// are 1-bit so color isn't part of it; that gets picked when drawing.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct GlyphKey {
+    // `Graphics` numbers its fonts
+    font: usize,
+    c: char,
+    scale: Scale,
+}
+
+/// A glyph ready to draw. Whole scales stay crisp 1-bit pixels; anything in
+/// between gets shaded edges so strokes don't come out uneven.
+pub enum GlyphBitmap {
+    Mono(MonoBitmap),
+    Smooth(AlphaBitmap),
+}
+
+impl GlyphBitmap {
+    /// (x, y) size of the glyph in px
+    pub fn dimensions(&self) -> (usize, usize) {
+        return match self {
+            GlyphBitmap::Mono(bitmap) => bitmap.dimensions(),
+            GlyphBitmap::Smooth(bitmap) => bitmap.dimensions(),
+        };
+    }
+
+    /// Draw the glyph in `color`. Same deal as `MonoBitmap::draw()`.
+    pub fn draw(
+        &self,
+        target: &mut dyn RenderTarget,
+        encoder: &PixelEncoder,
+        color: Color,
+        start: (usize, usize),
+        clip: Rect,
+    ) {
+        match self {
+            GlyphBitmap::Mono(bitmap) => bitmap.draw(target, encoder.encode(color), start, clip),
+            GlyphBitmap::Smooth(bitmap) => bitmap.draw(target, encoder, color, start, clip),
+        }
+    }
+}
+
+struct CachedGlyph {
+    bitmap: GlyphBitmap,
+    // value of `GlyphCache::clock` the last time this glyph was drawn
+    last_used: u64,
+}
+
+/// Scaled glyph bitmaps, kept around so drawing the same character again
+/// doesn't allocate. Once the glyphs take up more than `max_bytes` the ones
+/// that haven't been drawn for the longest are thrown out.
+pub struct GlyphCache {
+    glyphs: BTreeMap<GlyphKey, CachedGlyph>,
+    max_bytes: usize,
+    used_bytes: usize,
+    clock: u64,
+}
+
+impl GlyphCache {
+    pub fn new(max_bytes: usize) -> GlyphCache {
+        GlyphCache {
+            glyphs: BTreeMap::new(),
+            max_bytes: max_bytes,
+            used_bytes: 0,
+            clock: 0,
+        }
+    }
+
+    /// The bitmap for `c` in `font` scaled by `scale`. Rendered and cached
+    /// the first time it's asked for. `font_id` tells fonts apart in the
+    /// cache.
+    pub fn get(&mut self, font_id: usize, font: &Font, c: char, scale: Scale) -> &GlyphBitmap {
+        self.clock += 1;
+        let key = GlyphKey {
+            font: font_id,
+            c: c,
+            scale: scale,
+        };
+
+        if !self.glyphs.contains_key(&key) {
+            let glyph = font.glyph(c);
+            let bitmap = if scale.is_whole() {
+                GlyphBitmap::Mono(glyph.scale(scale.whole_part()))
+            } else {
+                let (cols, rows) = glyph.dimensions();
+                GlyphBitmap::Smooth(glyph.smooth((scale.apply(cols), scale.apply(rows))))
+            };
+            let size = GlyphCache::size_of(&bitmap);
+            self.evict(size);
+            self.used_bytes += size;
+            self.glyphs.insert(
+                key,
+                CachedGlyph {
+                    bitmap: bitmap,
+                    last_used: 0,
+                },
+            );
+        }
+
+        let glyph = self.glyphs.get_mut(&key).expect("glyph was just cached");
+        glyph.last_used = self.clock;
+        return &glyph.bitmap;
+    }
+
+    /// Forget every glyph. Handy if the font changes out from under us.
+    pub fn clear(&mut self) {
+        self.glyphs.clear();
+        self.used_bytes = 0;
+    }
+
+    /// Throw out least recently used glyphs until `incoming` more bytes fit.
+    /// A single glyph bigger than the whole cache still gets cached; it'll be
+    /// the first to go next time.
+    fn evict(&mut self, incoming: usize) {
+        while self.used_bytes + incoming > self.max_bytes {
+            let oldest = self
+                .glyphs
+                .iter()
+                .min_by_key(|(_, glyph)| glyph.last_used)
+                .map(|(key, _)| *key);
+            let key = match oldest {
+                Some(value) => value,
+                None => return,
+            };
+            if let Some(glyph) = self.glyphs.remove(&key) {
+                self.used_bytes -= GlyphCache::size_of(&glyph.bitmap);
+            }
+        }
+    }
+
+    fn size_of(bitmap: &GlyphBitmap) -> usize {
+        let (cols, rows) = bitmap.dimensions();
+        return match bitmap {
+            GlyphBitmap::Mono(_) => ((cols + 7) / 8) * rows,
+            GlyphBitmap::Smooth(_) => cols * rows,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::core::font::{self, GlyphMetrics};
+
+    fn cached(cache: &GlyphCache, c: char, scale: usize) -> bool {
+        let key = GlyphKey {
+            font: 0,
+            c: c,
+            scale: Scale::whole(scale),
+        };
+        return cache.glyphs.contains_key(&key);
+    }
+
+    /// Room for `n` glyphs at scale 1
+    fn cache_for(n: usize) -> GlyphCache {
+        return GlyphCache::new(
+            n * GlyphCache::size_of(&GlyphBitmap::Mono(font::get_glyph('A').scale(1))),
+        );
+    }
+
+    #[test]
+    fn least_recently_used_goes_first() {
+        let mut cache = cache_for(2);
+        let font = Font::builtin();
+        cache.get(0, &font, 'A', Scale::whole(1));
+        cache.get(0, &font, 'B', Scale::whole(1));
+        cache.get(0, &font, 'C', Scale::whole(1));
+        assert!(!cached(&cache, 'A', 1));
+        assert!(cached(&cache, 'B', 1));
+        assert!(cached(&cache, 'C', 1));
+        assert!(cache.used_bytes <= cache.max_bytes);
+    }
+
+    #[test]
+    fn hits_count_as_use() {
+        let mut cache = cache_for(2);
+        let font = Font::builtin();
+        cache.get(0, &font, 'A', Scale::whole(1));
+        cache.get(0, &font, 'B', Scale::whole(1));
+        cache.get(0, &font, 'A', Scale::whole(1));
+        cache.get(0, &font, 'C', Scale::whole(1));
+        assert!(cached(&cache, 'A', 1));
+        assert!(!cached(&cache, 'B', 1));
+    }
+
+    #[test]
+    fn scale_is_part_of_the_key() {
+        let mut cache = cache_for(8);
+        let font = Font::builtin();
+        cache.get(0, &font, 'A', Scale::whole(1));
+        cache.get(0, &font, 'A', Scale::whole(2));
+        assert_eq!(cache.glyphs.len(), 2);
+        let (cols, rows) = cache.get(0, &font, 'A', Scale::whole(2)).dimensions();
+        assert_eq!((cols, rows), (2 * font::FONT_WIDTH, 2 * font::FONT_HEIGHT));
+    }
+
+    #[test]
+    fn oversized_glyphs_still_get_cached() {
+        let mut cache = cache_for(1);
+        let font = Font::builtin();
+        cache.get(0, &font, 'A', Scale::whole(1));
+        cache.get(0, &font, 'A', Scale::whole(3));
+        assert!(cached(&cache, 'A', 3));
+        assert!(!cached(&cache, 'A', 1));
+    }
+
+    #[test]
+    fn fractional_scales_get_smoothed() {
+        let mut cache = cache_for(8);
+        let font = Font::builtin();
+        let glyph = cache.get(0, &font, 'A', Scale::ratio(3, 2));
+        assert!(matches!(glyph, GlyphBitmap::Smooth(_)));
+        let (cols, rows) = glyph.dimensions();
+        assert_eq!(
+            (cols, rows),
+            (
+                Scale::ratio(3, 2).apply(font::FONT_WIDTH),
+                Scale::ratio(3, 2).apply(font::FONT_HEIGHT)
+            )
+        );
+        assert!(matches!(
+            cache.get(0, &font, 'A', Scale::whole(2)),
+            GlyphBitmap::Mono(_)
+        ));
+    }
+
+    #[test]
+    fn font_is_part_of_the_key() {
+        let mut cache = cache_for(8);
+        let builtin = Font::builtin();
+        // one 3x2 glyph for everything
+        let glyph = MonoBitmap::from_rows(3, &[0b101, 0b010]);
+        let metrics = vec![GlyphMetrics::from_ink(&glyph)];
+        let tiny = Font::new((3, 2), vec![glyph], metrics, BTreeMap::new(), 0);
+        cache.get(0, &builtin, 'A', Scale::whole(2));
+        assert_eq!(
+            cache.get(1, &tiny, 'A', Scale::whole(2)).dimensions(),
+            (6, 4)
+        );
+        assert_eq!(cache.glyphs.len(), 2);
+
+        cache.clear();
+        assert!(cache.glyphs.is_empty());
+        assert_eq!(cache.used_bytes, 0);
+    }
+}
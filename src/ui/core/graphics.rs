@@ -1,11 +1,31 @@
-use uefi::proto::console::gop::GraphicsOutput;
-use uefi::ResultExt;
+use uefi::proto::console::gop::PixelFormat;
 
-use no_std_compat::prelude::v1::vec;
+use no_std_compat::cmp;
+use no_std_compat::prelude::v1::{vec, Box};
+use no_std_compat::string::String;
+use no_std_compat::vec::Vec;
 
-use crate::bmp::{Bitmap, Pixel};
-use crate::ui::core::font;
+use crate::bitmap::{Bitmap, DEBUG_BORDER};
+use crate::ui::core::codec::bmp;
+use crate::ui::core::font::{self, Font};
+use crate::ui::core::glyph_cache::GlyphCache;
+use crate::ui::core::layer::Layer;
+use crate::ui::core::rect::Rect;
+use crate::ui::core::render_target::{MemoryTarget, PixelEncoder, RenderTarget};
+use crate::ui::core::resolution::ResolutionPolicy;
+use crate::ui::core::rotation::Rotation;
+use crate::ui::core::scale::Scale;
+use crate::ui::core::text::{self, TextOptions};
 
+/// Past this many separate damaged regions it's cheaper to push one big
+/// rectangle than to make a bunch of small `blt()` calls.
+const MAX_DAMAGE_RECTS: usize = 16;
+
+/// How much memory rendered glyphs get to hold onto. A 2x glyph is 66 bytes so
+/// this is a couple thousand of them.
+const GLYPH_CACHE_BYTES: usize = 128 * 1024;
+
+#[derive(Clone, Copy)]
 pub enum ColorType {
     Foreground = 0,
     Background,
@@ -14,7 +34,83 @@ pub enum ColorType {
     BorderFocused,
 }
 
+/// [red, green, blue]
 pub type Color = [u8; 3];
+
+/// Mix `over` onto `under`. `alpha` is how much of `over` shows through: 0 is
+/// none of it, 255 is nothing but it.
+pub fn blend(under: Color, over: Color, alpha: u8) -> Color {
+    let alpha = u16::from(alpha);
+    let mut blended = [0; 3];
+    for i in 0..3 {
+        let mixed = (u16::from(over[i]) * alpha) + (u16::from(under[i]) * (255 - alpha));
+        blended[i] = ((mixed + 127) / 255) as u8;
+    }
+    return blended;
+}
+
+/// Something to draw with: either a slot in the theme's `ColorScheme` or a
+/// specific `Color`. Drawing methods take `impl Into<Paint>` so either one
+/// can be passed straight in.
+#[derive(Clone, Copy)]
+pub enum Paint {
+    Theme(ColorType),
+    Raw(Color),
+}
+
+impl From<ColorType> for Paint {
+    fn from(color: ColorType) -> Paint {
+        return Paint::Theme(color);
+    }
+}
+
+impl From<Color> for Paint {
+    fn from(color: Color) -> Paint {
+        return Paint::Raw(color);
+    }
+}
+
+/// Which way a gradient runs. `Horizontal` goes left to right, `Vertical` top
+/// to bottom.
+#[derive(Clone, Copy)]
+#[allow(dead_code)]
+pub enum GradientDirection {
+    Horizontal,
+    Vertical,
+}
+
+/// Integer square root, rounded down. No floats in here.
+fn isqrt(n: usize) -> usize {
+    if n < 2 {
+        return n;
+    }
+    // newton's method converges from above
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + (n / x)) / 2;
+    }
+    return x;
+}
+
+/// How far in from the left and right edges row `row` of a `height` tall
+/// rectangle with corners of radius `radius` starts.
+fn rounded_inset(radius: usize, height: usize, row: usize) -> usize {
+    let from_edge = if row < radius {
+        row
+    } else if row >= height - radius {
+        height - 1 - row
+    } else {
+        return 0;
+    };
+    // work in half pixels so we measure from pixel centers: the corner circle
+    // is 2 * radius across and this row's center is `d` from its middle
+    let d = (2 * (radius - from_edge)) - 1;
+    let half_width = isqrt((4 * radius * radius) - (d * d));
+    return radius - ((half_width + 1) / 2);
+}
+
 pub struct ColorScheme([Color; 5]);
 
 impl ColorScheme {
@@ -50,46 +146,415 @@ pub enum FontSize {
     P,
 }
 
-pub struct FontSizes([usize; 3]);
+/// How much each `FontSize` scales the font up. Whole numbers or fractions,
+/// e.g. `FontSizes::new(3, 2, Scale::ratio(3, 2))`.
+pub struct FontSizes([Scale; 3]);
 
 impl FontSizes {
     #[allow(dead_code)]
     pub fn default() -> FontSizes {
-        FontSizes([2, 2, 2])
+        FontSizes([Scale::whole(2); 3])
     }
 
-    pub fn new(h1: usize, h2: usize, text: usize) -> FontSizes {
-        FontSizes([h1, h2, text])
+    pub fn new(h1: impl Into<Scale>, h2: impl Into<Scale>, text: impl Into<Scale>) -> FontSizes {
+        FontSizes([h1.into(), h2.into(), text.into()])
     }
 
-    pub fn get(&self, size: FontSize) -> usize {
+    pub fn get(&self, size: FontSize) -> Scale {
         let idx: usize = size as usize;
         assert!(idx < self.0.len());
         return self.0[idx];
     }
 }
 
-// maybe later i want to add multiple fonts lol
 pub struct Theme {
     pub font_sizes: FontSizes,
     pub color_scheme: ColorScheme,
+    /// Name of the font to draw text with: `font::BUILTIN` or one added with
+    /// `Graphics::add_font()`. Unknown names get the built-in font.
+    pub font: String,
 }
 
 /// `Graphics` is passed around to `Widget`s rather than uefi's `GraphicsOutput`
 /// because it can hold onto custom state (currently theme and font size).
+/// It draws into any `RenderTarget`, so it doesn't need real firmware to work.
+///
+/// Drawing happens in an off-screen back buffer. Nothing shows up on the
+/// `RenderTarget` until `present()` is called, so half-finished frames never
+/// flicker on screen. Every draw call records the region it touched and
+/// `present()` only pushes those regions.
+///
+/// The back buffer itself is composited from a stack of `Layer`s, one per
+/// `Widget` on the `UIStack`. Draw calls go to the active `Layer`. Popping a
+/// `Layer` reveals whatever it covered without anything having to redraw.
+///
+/// Drawing is clipped to the screen and to whatever `push_clip()` asked for,
+/// so nothing can write outside the framebuffer.
+///
+/// The picture can be turned with `set_rotation()` for panels that aren't
+/// mounted the way the firmware thinks. Everything but `present()` works in
+/// logical (rotated) coordinates, so nothing drawing has to know.
 ///
 /// Exposes methods for setting the session's resolution and drawing various things.
 pub struct Graphics<'a> {
-    gop: &'a mut GraphicsOutput<'a>,
+    target: Box<dyn RenderTarget + 'a>,
+    back_buffer: MemoryTarget,
+    encoder: PixelEncoder,
+    damage: Vec<Rect>,
+    clip_stack: Vec<Rect>,
+    layers: Vec<Layer>,
+    active_layer: usize,
+    rotation: Rotation,
+    glyphs: GlyphCache,
+    // every font `Theme::font` can name, built-in first
+    fonts: Vec<(String, Font)>,
     pub theme: Theme,
 }
 
 impl<'a> Graphics<'a> {
-    pub fn new(gop: &'a mut GraphicsOutput<'a>, theme: Theme) -> Graphics<'a> {
-        Graphics {
-            gop: gop,
+    pub fn new(target: Box<dyn RenderTarget + 'a>, theme: Theme) -> Graphics<'a> {
+        let back_buffer = Graphics::make_back_buffer(target.as_ref());
+        let encoder = PixelEncoder::for_target(&back_buffer);
+        // no rotation yet so logical and physical are the same
+        let layers = vec![Layer::new(back_buffer.resolution())];
+        let mut graphics = Graphics {
+            target: target,
+            back_buffer: back_buffer,
+            encoder: encoder,
+            damage: Vec::new(),
+            clip_stack: Vec::new(),
+            layers: layers,
+            active_layer: 0,
+            rotation: Rotation::Deg0,
+            glyphs: GlyphCache::new(GLYPH_CACHE_BYTES),
+            fonts: vec![(String::from(font::BUILTIN), Font::builtin())],
             theme: theme,
+        };
+        graphics.invalidate(graphics.screen());
+        return graphics;
+    }
+
+    /// The back buffer stores pixels exactly like the target does so
+    /// presenting is just a copy. Targets without a linear framebuffer
+    /// (`PixelFormat::BltOnly`) get a BGR back buffer since that's what
+    /// `GraphicsOutput::blt()` takes; every frame then reaches the screen
+    /// through `present()` alone.
+    fn make_back_buffer(target: &dyn RenderTarget) -> MemoryTarget {
+        let format = match target.pixel_format() {
+            PixelFormat::BltOnly => {
+                log::info!("no linear framebuffer, drawing through blt()");
+                PixelFormat::Bgr
+            }
+            value => value,
+        };
+        return MemoryTarget::with_format(target.resolution(), format, target.pixel_bitmask());
+    }
+
+    /// The whole screen as a `Rect`
+    pub fn screen(&self) -> Rect {
+        return Rect::new((0, 0), self.resolution());
+    }
+
+    /// (x, y) size of the screen in px, after rotation
+    pub fn resolution(&self) -> (usize, usize) {
+        return self.rotation.logical_size(self.back_buffer.resolution());
+    }
+
+    /// Every resolution the `RenderTarget` can switch to, after rotation
+    pub fn available_modes(&self) -> Vec<(usize, usize)> {
+        return self
+            .target
+            .available_resolutions()
+            .iter()
+            .map(|mode| self.rotation.logical_size(*mode))
+            .collect();
+    }
+
+    /// How far the picture is turned; see `set_rotation()`
+    pub fn rotation(&self) -> Rotation {
+        return self.rotation;
+    }
+
+    /// Turn the picture. The resolution changes with it if it's turned
+    /// sideways, and every `Layer` is rebuilt to match, which clears them.
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+        let resolution = self.resolution();
+        for layer in self.layers.iter_mut() {
+            layer.set_resolution(resolution);
+        }
+        self.damage.clear();
+        self.invalidate(self.screen());
+    }
+
+    /// Mark `rect` as needing to be pushed to the screen on the next
+    /// `present()`. Drawing does this on its own; this is for when the screen
+    /// needs refreshing without anything being drawn.
+    ///
+    /// Overlapping regions are merged so no pixel gets pushed twice.
+    pub fn invalidate(&mut self, rect: Rect) {
+        let mut rect = match rect.intersection(&self.screen()) {
+            Some(value) => value,
+            None => return,
+        };
+        // growing `rect` can make it overlap regions it didn't before, so keep
+        // absorbing until nothing overlaps
+        while let Some(idx) = self.damage.iter().position(|r| r.intersects(&rect)) {
+            rect = rect.union(&self.damage.swap_remove(idx));
+        }
+        self.damage.push(rect);
+
+        if self.damage.len() > MAX_DAMAGE_RECTS {
+            let bounding = self
+                .damage
+                .iter()
+                .fold(self.damage[0], |acc, r| acc.union(r));
+            self.damage = vec![bounding];
+        }
+    }
+
+    /// Composite every region drawn since the last `present()` into the back
+    /// buffer and push it to the `RenderTarget`. On real hardware that's one
+    /// `GraphicsOutput::blt()` per damaged region.
+    pub fn present(&mut self) {
+        let stride = self.back_buffer.stride();
+        let logical = self.resolution();
+        let damage: Vec<Rect> = self.damage.drain(..).collect();
+        for rect in damage {
+            let physical = self.rotation.rect_to_physical(rect, logical);
+            self.composite(rect, physical);
+            self.target.blit(
+                self.back_buffer.pixels(),
+                stride,
+                physical.top_left(),
+                physical.top_left(),
+                physical.dimensions(),
+            );
+        }
+    }
+
+    /// Rebuild `rect` of the back buffer from the `Layer`s, bottom to top. A
+    /// `Layer`'s `dim` darkens it along with everything under it. `physical`
+    /// is where `rect` ends up on the panel; this is where rotation happens.
+    fn composite(&mut self, rect: Rect, physical: Rect) {
+        let background = self.theme.color_scheme.get(ColorType::Background);
+        // a `Layer` that hasn't drawn anything in `rect` only matters there if
+        // it's dimmed, and then its pixels don't need looking at
+        let layers: Vec<(&Layer, bool)> = self
+            .layers
+            .iter()
+            .map(|layer| (layer, layer.extent.intersects(&rect)))
+            .filter(|(layer, drawn)| *drawn || layer.dim > 0)
+            .collect();
+
+        let logical = self.resolution();
+        for y in physical.y..physical.bottom() {
+            for x in physical.x..physical.right() {
+                let pos = self.rotation.to_logical((x, y), logical);
+                let mut color: Color = [0, 0, 0];
+                for (layer, drawn) in layers.iter() {
+                    if *drawn {
+                        if let Some(value) = layer.pixel(pos) {
+                            color = value;
+                        }
+                    }
+                    if layer.dim > 0 {
+                        color = blend(color, background, layer.dim);
+                    }
+                }
+                let value = self.encoder.encode(color);
+                self.back_buffer.write_pixel((x, y), value);
+            }
+        }
+    }
+
+    /// Whatever the last `present()` put on screen, as a 24-bit BMP file. Comes
+    /// out of the back buffer, turned the way the panel is meant to be read.
+    pub fn capture(&self) -> Vec<u8> {
+        let logical = self.resolution();
+        let stride = self.back_buffer.stride();
+        let frame = self.back_buffer.pixels();
+        let mut pixels: Vec<Color> = Vec::with_capacity(logical.0 * logical.1);
+        for y in 0..logical.1 {
+            for x in 0..logical.0 {
+                let (px, py) = self.rotation.to_physical((x, y), logical);
+                pixels.push(self.encoder.decode(frame[(py * stride) + px]));
+            }
+        }
+        return bmp::encode(logical, &pixels);
+    }
+
+    /// Put a new, empty `Layer` on top of the others and start drawing to it.
+    pub fn push_layer(&mut self) {
+        self.layers.push(Layer::new(self.resolution()));
+        self.active_layer = self.layers.len() - 1;
+    }
+
+    /// Throw away the top `Layer`. Whatever it covered gets composited again
+    /// on the next `present()`. Drawing moves to the new top `Layer` if it was
+    /// going to the one that's gone.
+    pub fn pop_layer(&mut self) {
+        assert!(self.layers.len() > 1, "can't pop the last layer");
+        let layer = self.layers.pop().expect("should have layers");
+        if self.active_layer >= self.layers.len() {
+            self.active_layer = self.layers.len() - 1;
+        }
+        self.invalidate(layer.extent);
+    }
+
+    /// Move the `Layer` at index `from` so it ends up at index `to`, shuffling
+    /// the ones in between. Index 0 is the bottom. The active `Layer` follows.
+    pub fn move_layer(&mut self, from: usize, to: usize) {
+        let active = self.active_layer;
+        let layer = self.layers.remove(from);
+        self.layers.insert(to, layer);
+        if active == from {
+            self.active_layer = to;
+        } else if from < active && active <= to {
+            self.active_layer -= 1;
+        } else if to <= active && active < from {
+            self.active_layer += 1;
+        }
+        // dimming carries through to everything underneath, so who knows
+        self.invalidate(self.screen());
+    }
+
+    /// Send draw calls to the `Layer` at `idx` (0 is the bottom).
+    pub fn set_active_layer(&mut self, idx: usize) {
+        assert!(idx < self.layers.len(), "no such layer");
+        self.active_layer = idx;
+    }
+
+    pub fn layer_count(&self) -> usize {
+        return self.layers.len();
+    }
+
+    /// Forget everything drawn on the active `Layer`.
+    pub fn clear_layer(&mut self) {
+        let layer = &mut self.layers[self.active_layer];
+        let extent = layer.extent;
+        layer.clear();
+        self.invalidate(extent);
+    }
+
+    /// Blend `alpha` (out of 255) of the background color over the `Layer` at
+    /// `idx` and everything below it when compositing.
+    pub fn set_layer_dim(&mut self, idx: usize, alpha: u8) {
+        if self.layers[idx].dim != alpha {
+            self.layers[idx].dim = alpha;
+            self.invalidate(self.screen());
+        }
+    }
+
+    /// Make `font` available to `Theme::font` as `name`, replacing whatever
+    /// font already had that name. Nothing already drawn changes.
+    pub fn add_font(&mut self, name: &str, font: Font) {
+        match self.fonts.iter().position(|(n, _)| n == name) {
+            Some(idx) => {
+                self.fonts[idx].1 = font;
+                // glyphs from the old font are cached under the same index
+                self.glyphs.clear();
+            }
+            None => self.fonts.push((String::from(name), font)),
+        }
+    }
+
+    /// Index into `fonts` of the font the theme names
+    fn font_index(&self) -> usize {
+        return self
+            .fonts
+            .iter()
+            .position(|(name, _)| *name == self.theme.font)
+            .unwrap_or(0);
+    }
+
+    /// The font text is drawn with
+    pub fn font(&self) -> &Font {
+        return &self.fonts[self.font_index()].1;
+    }
+
+    /// (x, y) size in px of one character cell at `size`, in the theme's font.
+    /// Lay text out with this rather than assuming a font.
+    pub fn char_dimensions(&self, size: FontSize) -> (usize, usize) {
+        let scale = self.theme.font_sizes.get(size);
+        let (width, height) = self.font().cell();
+        return (scale.apply(width), scale.apply(height));
+    }
+
+    /// How far `c` moves the pen at `size` when setting proportional text
+    pub fn char_advance(&self, c: char, size: FontSize) -> usize {
+        return self
+            .theme
+            .font_sizes
+            .get(size)
+            .apply(self.font().metrics(c).advance);
+    }
+
+    /// Width in px of `text` set proportionally at `size`
+    pub fn text_width(&self, text: &str, size: FontSize) -> usize {
+        return text.chars().map(|c| self.char_advance(c, size)).sum();
+    }
+
+    /// (x, y) size in px `text` takes up at `size`: the widest line by however
+    /// many lines there are. Every `\n` starts a new line.
+    pub fn measure_text(&self, text: &str, size: FontSize) -> (usize, usize) {
+        let line_height = self.char_dimensions(size).1;
+        let mut dims = (0, 0);
+        for line in text.split('\n') {
+            dims.0 = cmp::max(dims.0, self.text_width(line, size));
+            dims.1 += line_height;
+        }
+        return dims;
+    }
+
+    /// Draw `text` inside `rect`, lined up according to `options`, which also
+    /// decides what happens to lines that are too wide. Anything that still
+    /// doesn't fit gets clipped to `rect`. `size` and `color` work like they
+    /// do for `write_text()`.
+    pub fn draw_text(
+        &mut self,
+        text: &str,
+        rect: Rect,
+        options: TextOptions,
+        size: FontSize,
+        color: ColorType,
+    ) {
+        let lines = text::lines(text, rect.width, options.overflow, |c| {
+            self.char_advance(c, size)
+        });
+        let line_height = self.char_dimensions(size).1;
+        let text_height = lines.len() * line_height;
+        let mut y = rect.y + text::y_offset(rect.height, text_height, options.valign);
+
+        self.push_clip(rect);
+        for line in lines {
+            let width = self.text_width(&line, size);
+            let x = rect.x + text::x_offset(rect.width, width, options.halign);
+            self.write_text(&line, (x, y), size, color);
+            y += line_height;
         }
+        self.pop_clip();
+    }
+
+    /// Set `text` proportionally on one line, each character as wide as it
+    /// needs rather than a whole cell. `top_left` is where the pen starts and
+    /// the top of the line. Returns how far the pen moved, i.e. `text_width()`.
+    pub fn write_text(
+        &mut self,
+        text: &str,
+        top_left: (usize, usize),
+        size: FontSize,
+        color: ColorType,
+    ) -> usize {
+        let scale = self.theme.font_sizes.get(size);
+        let mut pen = top_left.0;
+        for c in text.chars() {
+            let metrics = self.font().metrics(c);
+            let cell_x = pen.saturating_sub(scale.apply(metrics.bearing));
+            self.write_char(c, (cell_x, top_left.1), size, color);
+            pen += scale.apply(metrics.advance);
+        }
+        return pen - top_left.0;
     }
 
     /// Write a char `c` to the pixel `top_left` scaled by `size` and in `color`.
@@ -104,9 +569,20 @@ impl<'a> Graphics<'a> {
     ) {
         let color = self.theme.color_scheme.get(color);
         let size = self.theme.font_sizes.get(size);
-        let bmp = font::get_bitmap(c, color);
-        let mut bmp = Bitmap::scale(&bmp, size);
-        bmp.draw(self.gop, top_left);
+        let clip = self.clip();
+        let font_id = self.font_index();
+        let glyph = self.glyphs.get(font_id, &self.fonts[font_id].1, c, size);
+        let area = Rect::new(top_left, glyph.dimensions());
+        let layer = &mut self.layers[self.active_layer];
+        glyph.draw(layer, &Layer::encoder(), color, top_left, clip);
+        self.damage_shape(area);
+
+        unsafe {
+            // accessing a mutable static
+            if DEBUG_BORDER {
+                self.draw_outline(area, 1, [255, 255, 255]);
+            }
+        }
     }
 
     /// Just draws a little guy
@@ -119,34 +595,925 @@ impl<'a> Graphics<'a> {
         dimensions_px: (usize, usize),
         border: Option<ColorType>,
     ) {
-        let color = self.theme.color_scheme.get(color);
-        let border = border.map(|x| self.theme.color_scheme.get(x));
-
-        let cols = dimensions_px.0;
-        let rows = dimensions_px.1;
-        let mut bmp = Bitmap::new(
-            rows,
-            cols,
-            Pixel::from_u8_vec(vec![1; cols * rows], color),
-            border,
-        );
-        bmp.draw(self.gop, top_left);
+        let rect = Rect::new(top_left, dimensions_px);
+        self.fill_rect(rect, color);
+        unsafe {
+            // accessing a mutable static
+            if border.is_none() && DEBUG_BORDER {
+                self.draw_outline(rect, 1, [255, 255, 255]);
+            }
+        }
+        if let Some(value) = border {
+            self.draw_outline(rect, 1, value);
+        }
     }
 
-    /// Set the resolution if the specified value is among the list of available
-    /// modes. Just yell into the console if it doesn't work.
-    pub fn set_resolution(&mut self, resolution: (usize, usize)) {
-        let mode = self
-            .gop
-            .modes()
-            .map(|mode| mode.expect("failed to get mode"))
-            .find(|mode| mode.info().resolution() == resolution);
-        if let Some(value) = mode {
-            self.gop
-                .set_mode(&value)
-                .expect_success("failed to set mode");
+    /// Blend `color` over everything already drawn in `rect` on the active
+    /// `Layer`. `alpha` is how strong the tint is: 0 changes nothing, 255
+    /// paints right over it. Good for dimming things that aren't in focus.
+    pub fn tint(&mut self, rect: Rect, color: impl Into<Paint>, alpha: u8) {
+        let color = self.resolve_paint(color.into());
+        let visible = match rect.intersection(&self.clip()) {
+            Some(value) => value,
+            None => return,
+        };
+        let encoder = Layer::encoder();
+        let layer = &mut self.layers[self.active_layer];
+        for y in visible.y..visible.bottom() {
+            for x in visible.x..visible.right() {
+                // leave the see-through bits see-through
+                if let Some(under) = layer.pixel((x, y)) {
+                    layer.write_pixel((x, y), encoder.encode(blend(under, color, alpha)));
+                }
+            }
+        }
+        self.invalidate(visible);
+    }
+
+    /// Draw a one pixel wide line from `from` to `to`, both ends included.
+    pub fn draw_line(&mut self, from: (usize, usize), to: (usize, usize), color: impl Into<Paint>) {
+        let value = self.encode_paint(color.into());
+        let clip = self.clip();
+        let (mut x, mut y) = (from.0 as isize, from.1 as isize);
+        let (x1, y1) = (to.0 as isize, to.1 as isize);
+
+        // bresenham, any octant
+        let dx = (x1 - x).abs();
+        let dy = -(y1 - y).abs();
+        let step_x = if x < x1 { 1 } else { -1 };
+        let step_y = if y < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        loop {
+            self.fill_span(clip, y, x, x + 1, value);
+            if x == x1 && y == y1 {
+                break;
+            }
+            let err2 = 2 * err;
+            if err2 >= dy {
+                err += dy;
+                x += step_x;
+            }
+            if err2 <= dx {
+                err += dx;
+                y += step_y;
+            }
+        }
+
+        let top_left = (cmp::min(from.0, to.0), cmp::min(from.1, to.1));
+        let bottom_right = (cmp::max(from.0, to.0), cmp::max(from.1, to.1));
+        self.damage_shape(Rect::new(
+            top_left,
+            (
+                bottom_right.0 - top_left.0 + 1,
+                bottom_right.1 - top_left.1 + 1,
+            ),
+        ));
+    }
+
+    /// Fill `rect` with a single color.
+    pub fn fill_rect(&mut self, rect: Rect, color: impl Into<Paint>) {
+        self.fill_rounded_rect(rect, 0, color);
+    }
+
+    /// Draw just the edges of `rect`, `thickness` px wide and all inside `rect`.
+    pub fn draw_outline(&mut self, rect: Rect, thickness: usize, color: impl Into<Paint>) {
+        self.draw_rounded_outline(rect, 0, thickness, color);
+    }
+
+    /// Fill `rect` with its corners rounded off to `radius` px.
+    pub fn fill_rounded_rect(&mut self, rect: Rect, radius: usize, color: impl Into<Paint>) {
+        let value = self.encode_paint(color.into());
+        let clip = self.clip();
+        let radius = cmp::min(radius, cmp::min(rect.width, rect.height) / 2);
+        for row in 0..rect.height {
+            let inset = rounded_inset(radius, rect.height, row);
+            self.fill_span(
+                clip,
+                (rect.y + row) as isize,
+                (rect.x + inset) as isize,
+                (rect.right() - inset) as isize,
+                value,
+            );
+        }
+        self.damage_shape(rect);
+    }
+
+    /// Draw the edges of `rect` with its corners rounded off to `radius` px.
+    /// The outline is `thickness` px wide and all inside `rect`.
+    pub fn draw_rounded_outline(
+        &mut self,
+        rect: Rect,
+        radius: usize,
+        thickness: usize,
+        color: impl Into<Paint>,
+    ) {
+        let value = self.encode_paint(color.into());
+        let clip = self.clip();
+        let radius = cmp::min(radius, cmp::min(rect.width, rect.height) / 2);
+
+        // whatever's left in the middle stays untouched. its corners follow
+        // the outer ones so the outline keeps the same thickness all the way
+        // around
+        let inner_radius = radius.saturating_sub(thickness);
+        let inner = if 2 * thickness < cmp::min(rect.width, rect.height) {
+            Some(Rect::new(
+                (rect.x + thickness, rect.y + thickness),
+                (rect.width - 2 * thickness, rect.height - 2 * thickness),
+            ))
         } else {
+            None
+        };
+
+        for row in 0..rect.height {
+            let y = rect.y + row;
+            let left = (rect.x + rounded_inset(radius, rect.height, row)) as isize;
+            let right = (rect.right() - rounded_inset(radius, rect.height, row)) as isize;
+            match inner {
+                Some(inner) if y >= inner.y && y < inner.bottom() => {
+                    let inset = rounded_inset(inner_radius, inner.height, y - inner.y);
+                    let hole_left = (inner.x + inset) as isize;
+                    let hole_right = (inner.right() - inset) as isize;
+                    self.fill_span(clip, y as isize, left, hole_left, value);
+                    self.fill_span(clip, y as isize, hole_right, right, value);
+                }
+                _ => self.fill_span(clip, y as isize, left, right, value),
+            }
+        }
+        self.damage_shape(rect);
+    }
+
+    /// Fill a circle around `center`. `radius` 0 is a single pixel.
+    pub fn fill_circle(&mut self, center: (usize, usize), radius: usize, color: impl Into<Paint>) {
+        self.draw_circle(center, radius, radius + 1, color);
+    }
+
+    /// Draw a ring around `center`, `thickness` px wide going in from `radius`.
+    pub fn draw_circle(
+        &mut self,
+        center: (usize, usize),
+        radius: usize,
+        thickness: usize,
+        color: impl Into<Paint>,
+    ) {
+        let value = self.encode_paint(color.into());
+        let clip = self.clip();
+        let (cx, cy) = (center.0 as isize, center.1 as isize);
+        let r = radius as isize;
+        // pixels closer to the center than this aren't part of the ring
+        let hole = radius.checked_sub(thickness);
+
+        for dy in -r..=r {
+            let y = cy + dy;
+            let dy2 = (dy * dy) as usize;
+            let half = isqrt((radius * radius) - dy2) as isize;
+            match hole {
+                Some(hole) if dy2 <= hole * hole => {
+                    let hole_half = isqrt((hole * hole) - dy2) as isize;
+                    self.fill_span(clip, y, cx - half, cx - hole_half, value);
+                    self.fill_span(clip, y, cx + hole_half + 1, cx + half + 1, value);
+                }
+                _ => self.fill_span(clip, y, cx - half, cx + half + 1, value),
+            }
+        }
+
+        let left = center.0.saturating_sub(radius);
+        let top = center.1.saturating_sub(radius);
+        self.damage_shape(Rect::new(
+            (left, top),
+            (center.0 + radius + 1 - left, center.1 + radius + 1 - top),
+        ));
+    }
+
+    /// Fill `rect` with colors running smoothly from `from` to `to`.
+    pub fn fill_gradient(
+        &mut self,
+        rect: Rect,
+        from: impl Into<Paint>,
+        to: impl Into<Paint>,
+        direction: GradientDirection,
+    ) {
+        let from = self.resolve_paint(from.into());
+        let to = self.resolve_paint(to.into());
+        let clip = self.clip();
+        let encoder = Layer::encoder();
+        let steps = match direction {
+            GradientDirection::Horizontal => rect.width,
+            GradientDirection::Vertical => rect.height,
+        };
+        for step in 0..steps {
+            let alpha = if steps > 1 {
+                ((step * 255) / (steps - 1)) as u8
+            } else {
+                0
+            };
+            let value = encoder.encode(blend(from, to, alpha));
+            match direction {
+                GradientDirection::Horizontal => {
+                    let x = (rect.x + step) as isize;
+                    for y in rect.y..rect.bottom() {
+                        self.fill_span(clip, y as isize, x, x + 1, value);
+                    }
+                }
+                GradientDirection::Vertical => {
+                    self.fill_span(
+                        clip,
+                        (rect.y + step) as isize,
+                        rect.x as isize,
+                        rect.right() as isize,
+                        value,
+                    );
+                }
+            }
+        }
+        self.damage_shape(rect);
+    }
+
+    /// The actual `Color` a `Paint` stands for in the current theme
+    fn resolve_paint(&self, paint: Paint) -> Color {
+        return match paint {
+            Paint::Theme(color) => self.theme.color_scheme.get(color),
+            Paint::Raw(color) => color,
+        };
+    }
+
+    /// A `Paint` ready to be written to a `Layer`
+    fn encode_paint(&self, paint: Paint) -> u32 {
+        return Layer::encoder().encode(self.resolve_paint(paint));
+    }
+
+    /// Write `value` to row `y` of the active `Layer` from `x0` up to `x1`,
+    /// skipping whatever's outside `clip`. Coordinates can be off screen (even
+    /// negative) so shapes don't have to care. Nothing gets invalidated; see
+    /// `damage_shape()`.
+    fn fill_span(&mut self, clip: Rect, y: isize, x0: isize, x1: isize, value: u32) {
+        if y < clip.y as isize || y >= clip.bottom() as isize {
+            return;
+        }
+        let x0 = cmp::max(x0, clip.x as isize);
+        let x1 = cmp::min(x1, clip.right() as isize);
+        if x0 < x1 {
+            self.layers[self.active_layer].fill_span(y as usize, x0 as usize, x1 as usize, value);
+        }
+    }
+
+    /// Once a shape is done with `fill_span()`, record everywhere inside
+    /// `bounds` it could have touched.
+    fn damage_shape(&mut self, bounds: Rect) {
+        if let Some(visible) = bounds.intersection(&self.clip()) {
+            let layer = &mut self.layers[self.active_layer];
+            layer.extent = layer.extent.union(&visible);
+            self.invalidate(visible);
+        }
+    }
+
+    /// Draw `bmp` on the active `Layer`. Only the part inside the current clip
+    /// gets drawn (and later presented).
+    pub fn draw_bitmap(&mut self, bmp: &mut Bitmap, top_left: (usize, usize)) {
+        let clip = self.clip();
+        if let Some(visible) = Rect::new(top_left, bmp.dimensions()).intersection(&clip) {
+            let layer = &mut self.layers[self.active_layer];
+            bmp.draw(layer, &Layer::encoder(), top_left, visible);
+            layer.extent = layer.extent.union(&visible);
+            self.invalidate(visible);
+        }
+    }
+
+    /// Restrict drawing to `rect` until the matching `pop_clip()`. Clips nest:
+    /// the new clip is `rect` intersected with whatever clip was already in
+    /// place, so a child can't draw outside of its container.
+    pub fn push_clip(&mut self, rect: Rect) {
+        let clip = rect
+            .intersection(&self.clip())
+            .unwrap_or(Rect::new(rect.top_left(), (0, 0)));
+        self.clip_stack.push(clip);
+    }
+
+    /// Undo the last `push_clip()`.
+    pub fn pop_clip(&mut self) {
+        self.clip_stack
+            .pop()
+            .expect("pop_clip() without a push_clip()");
+    }
+
+    /// The area drawing is currently allowed in. Never bigger than the screen.
+    pub fn clip(&self) -> Rect {
+        let screen = self.screen();
+        return match self.clip_stack.last() {
+            Some(value) => value
+                .intersection(&screen)
+                .unwrap_or(Rect::new(value.top_left(), (0, 0))),
+            None => screen,
+        };
+    }
+
+    /// Pick a resolution out of `available_modes()` with `policy` and switch to
+    /// it. Returns the resolution in use afterwards, which is the old one if
+    /// nothing fit.
+    pub fn negotiate_resolution(&mut self, policy: ResolutionPolicy) -> (usize, usize) {
+        match policy.choose(&self.available_modes()) {
+            Some(value) => {
+                self.set_resolution(value);
+            }
+            None => {
+                log::info!(
+                    "no mode fits {:?}, staying at {:?}",
+                    policy,
+                    self.resolution()
+                );
+            }
+        }
+        return self.resolution();
+    }
+
+    /// Set the resolution if the specified value is among the list of available
+    /// modes. Just yell into the console and return false if it doesn't work.
+    /// The back buffer and every `Layer` are rebuilt to match, which clears them.
+    /// `resolution` is after rotation, same as `available_modes()`.
+    pub fn set_resolution(&mut self, resolution: (usize, usize)) -> bool {
+        // turning a size sideways twice gets it back
+        let physical = self.rotation.logical_size(resolution);
+        if !self.target.set_resolution(physical) {
             log::info!("resolution not found: {}x{}", resolution.0, resolution.1);
+            return false;
+        }
+        // the new mode may not even use the same pixel format
+        self.back_buffer = Graphics::make_back_buffer(self.target.as_ref());
+        self.encoder = PixelEncoder::for_target(&self.back_buffer);
+        for layer in self.layers.iter_mut() {
+            layer.set_resolution(resolution);
+        }
+        self.damage.clear();
+        self.invalidate(self.screen());
+        return true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitmap::MonoBitmap;
+    use crate::ui::core::font::GlyphMetrics;
+    use crate::ui::core::text::{HAlign, TextOverflow, VAlign};
+    use no_std_compat::collections::BTreeMap;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use uefi::proto::console::gop::{PixelBitmask, PixelFormat};
+
+    /// What made it to the screen
+    struct Shown {
+        target: MemoryTarget,
+        blits: Vec<Rect>,
+    }
+
+    /// Hands everything on to a `MemoryTarget` the test still has a hold of
+    struct Screen(Rc<RefCell<Shown>>);
+
+    impl RenderTarget for Screen {
+        fn resolution(&self) -> (usize, usize) {
+            return self.0.borrow().target.resolution();
+        }
+
+        fn stride(&self) -> usize {
+            return self.0.borrow().target.stride();
+        }
+
+        fn pixel_format(&self) -> PixelFormat {
+            return self.0.borrow().target.pixel_format();
+        }
+
+        fn pixel_bitmask(&self) -> Option<PixelBitmask> {
+            return self.0.borrow().target.pixel_bitmask();
+        }
+
+        fn available_resolutions(&self) -> Vec<(usize, usize)> {
+            return self.0.borrow().target.available_resolutions();
+        }
+
+        fn set_resolution(&mut self, resolution: (usize, usize)) -> bool {
+            return self.0.borrow_mut().target.set_resolution(resolution);
+        }
+
+        fn write_pixel(&mut self, pos: (usize, usize), value: u32) {
+            self.0.borrow_mut().target.write_pixel(pos, value);
+        }
+
+        fn read_pixel(&mut self, pos: (usize, usize)) -> u32 {
+            return self.0.borrow_mut().target.read_pixel(pos);
+        }
+
+        fn blit(
+            &mut self,
+            buffer: &[u32],
+            buffer_stride: usize,
+            src: (usize, usize),
+            dest: (usize, usize),
+            dims: (usize, usize),
+        ) {
+            let mut shown = self.0.borrow_mut();
+            shown.target.blit(buffer, buffer_stride, src, dest, dims);
+            shown.blits.push(Rect::new(dest, dims));
+        }
+    }
+
+    fn on_screen(resolution: (usize, usize)) -> (Graphics<'static>, Rc<RefCell<Shown>>) {
+        let theme = Theme {
+            font_sizes: FontSizes::new(1, 1, 1),
+            color_scheme: ColorScheme::new(
+                [255, 255, 255],
+                [0, 0, 0],
+                [0, 255, 0],
+                [90, 90, 90],
+                [0, 0, 255],
+            ),
+            font: String::from(font::BUILTIN),
+        };
+        let shown = Rc::new(RefCell::new(Shown {
+            target: MemoryTarget::new(resolution),
+            blits: Vec::new(),
+        }));
+        let graphics = Graphics::new(Box::new(Screen(shown.clone())), theme);
+        return (graphics, shown);
+    }
+
+    /// `present()` once to get the first full-screen push out of the way
+    fn presented(resolution: (usize, usize)) -> (Graphics<'static>, Rc<RefCell<Shown>>) {
+        let (mut graphics, shown) = on_screen(resolution);
+        graphics.present();
+        shown.borrow_mut().blits.clear();
+        return (graphics, shown);
+    }
+
+    fn lit(shown: &Rc<RefCell<Shown>>) -> usize {
+        return shown
+            .borrow()
+            .target
+            .pixels()
+            .iter()
+            .filter(|px| **px != 0)
+            .count();
+    }
+
+    #[test]
+    fn nothing_shows_until_present() {
+        let (mut graphics, shown) = on_screen((4, 4));
+        graphics.draw_rect(ColorType::Foreground, (1, 1), (2, 2), None);
+        assert_eq!(lit(&shown), 0);
+        graphics.present();
+        assert_eq!(lit(&shown), 4);
+        assert_eq!(shown.borrow().target.pixels()[5], 0xffffff);
+    }
+
+    #[test]
+    fn first_present_pushes_everything() {
+        let (mut graphics, shown) = on_screen((8, 6));
+        graphics.present();
+        assert_eq!(shown.borrow().blits, [Rect::new((0, 0), (8, 6))]);
+        // and after that there's nothing left to push
+        graphics.present();
+        assert_eq!(shown.borrow().blits.len(), 1);
+    }
+
+    #[test]
+    fn only_damage_gets_pushed() {
+        let (mut graphics, shown) = presented((8, 6));
+        graphics.draw_rect(ColorType::Foreground, (1, 1), (2, 2), None);
+        graphics.draw_rect(ColorType::Foreground, (5, 3), (2, 2), None);
+        // sneak something into the back buffer without marking it damaged
+        graphics.back_buffer.write_pixel((7, 0), 0x123456);
+        graphics.present();
+        assert_eq!(
+            shown.borrow().blits,
+            [Rect::new((1, 1), (2, 2)), Rect::new((5, 3), (2, 2))]
+        );
+        assert_eq!(lit(&shown), 8);
+        assert_eq!(shown.borrow().target.pixels()[7], 0);
+    }
+
+    #[test]
+    fn overlapping_damage_is_merged() {
+        let (mut graphics, shown) = presented((8, 6));
+        graphics.invalidate(Rect::new((0, 0), (3, 3)));
+        graphics.invalidate(Rect::new((5, 0), (2, 2)));
+        // overlaps the first, and once grown the second too
+        graphics.invalidate(Rect::new((2, 1), (4, 1)));
+        graphics.present();
+        assert_eq!(shown.borrow().blits, [Rect::new((0, 0), (7, 3))]);
+    }
+
+    #[test]
+    fn damage_is_clipped_to_the_screen() {
+        let (mut graphics, shown) = presented((8, 6));
+        graphics.invalidate(Rect::new((6, 4), (10, 10)));
+        graphics.invalidate(Rect::new((20, 20), (1, 1)));
+        graphics.present();
+        assert_eq!(shown.borrow().blits, [Rect::new((6, 4), (2, 2))]);
+    }
+
+    #[test]
+    fn too_much_damage_becomes_one_rect() {
+        let (mut graphics, shown) = presented((100, 4));
+        for i in 0..MAX_DAMAGE_RECTS + 1 {
+            graphics.invalidate(Rect::new((i * 2, 1), (1, 1)));
         }
+        graphics.present();
+        let last = MAX_DAMAGE_RECTS * 2;
+        assert_eq!(shown.borrow().blits, [Rect::new((0, 1), (last + 1, 1))]);
+    }
+
+    #[test]
+    fn clips_nest() {
+        let (mut graphics, shown) = presented((8, 6));
+        graphics.push_clip(Rect::new((2, 0), (10, 10)));
+        graphics.push_clip(Rect::new((0, 2), (4, 2)));
+        assert_eq!(graphics.clip(), Rect::new((2, 2), (2, 2)));
+        graphics.draw_rect(ColorType::Foreground, (0, 0), (8, 6), None);
+        graphics.pop_clip();
+        assert_eq!(graphics.clip(), Rect::new((2, 0), (6, 6)));
+        graphics.pop_clip();
+        assert_eq!(graphics.clip(), graphics.screen());
+        // only what was inside the clip got drawn or damaged
+        graphics.present();
+        assert_eq!(shown.borrow().blits, [Rect::new((2, 2), (2, 2))]);
+        assert_eq!(lit(&shown), 4);
+    }
+
+    #[test]
+    fn drawing_off_screen_is_fine() {
+        let (mut graphics, shown) = presented((8, 6));
+        graphics.draw_rect(ColorType::Foreground, (6, 5), (10, 10), None);
+        graphics.write_char('a', (100, 100), FontSize::P, ColorType::Foreground);
+        graphics.present();
+        assert_eq!(shown.borrow().blits, [Rect::new((6, 5), (2, 1))]);
+    }
+
+    #[test]
+    fn blend_ends() {
+        let (under, over) = ([10, 20, 30], [200, 100, 0]);
+        assert_eq!(blend(under, over, 0), under);
+        assert_eq!(blend(under, over, 255), over);
+        assert_eq!(blend([0, 0, 0], [255, 255, 255], 128), [128, 128, 128]);
+    }
+
+    #[test]
+    fn tint_blends_over_what_was_drawn() {
+        let (mut graphics, shown) = presented((4, 4));
+        graphics.draw_rect(ColorType::Foreground, (0, 0), (4, 4), None);
+        graphics.present();
+        shown.borrow_mut().blits.clear();
+        // background is black, so half of it halves the white
+        graphics.tint(Rect::new((1, 1), (2, 1)), ColorType::Background, 128);
+        graphics.present();
+        assert_eq!(shown.borrow().blits, [Rect::new((1, 1), (2, 1))]);
+        let pixels = shown.borrow().target.pixels().to_vec();
+        assert_eq!(pixels[5], 0x7f7f7f);
+        assert_eq!(pixels[6], 0x7f7f7f);
+        assert_eq!(pixels[4], 0xffffff);
+    }
+
+    #[test]
+    fn popping_a_layer_shows_whats_underneath() {
+        let (mut graphics, shown) = presented((4, 4));
+        graphics.draw_rect(ColorType::Foreground, (0, 0), (4, 4), None);
+        graphics.push_layer();
+        graphics.draw_rect(ColorType::Cursor, (1, 1), (2, 2), None);
+        graphics.present();
+        assert_eq!(shown.borrow().target.pixels()[5], 0x00ff00);
+        assert_eq!(shown.borrow().target.pixels()[0], 0xffffff);
+        shown.borrow_mut().blits.clear();
+
+        // nothing gets drawn on the bottom layer again
+        graphics.pop_layer();
+        graphics.present();
+        assert_eq!(shown.borrow().blits, [Rect::new((1, 1), (2, 2))]);
+        assert!(shown
+            .borrow()
+            .target
+            .pixels()
+            .iter()
+            .all(|px| *px == 0xffffff));
+    }
+
+    #[test]
+    fn clearing_a_layer_only_repaints_what_was_on_it() {
+        let (mut graphics, shown) = presented((4, 4));
+        graphics.draw_rect(ColorType::Foreground, (0, 0), (4, 4), None);
+        graphics.push_layer();
+        graphics.draw_rect(ColorType::Cursor, (0, 0), (1, 2), None);
+        graphics.present();
+        shown.borrow_mut().blits.clear();
+
+        graphics.clear_layer();
+        graphics.present();
+        assert_eq!(shown.borrow().blits, [Rect::new((0, 0), (1, 2))]);
+        assert_eq!(shown.borrow().target.pixels()[4], 0xffffff);
+    }
+
+    #[test]
+    fn layers_only_cover_what_they_drew() {
+        let (mut graphics, shown) = presented((4, 4));
+        graphics.draw_rect(ColorType::Foreground, (0, 0), (2, 4), None);
+        graphics.push_layer();
+        graphics.draw_rect(ColorType::Cursor, (2, 0), (2, 4), None);
+        graphics.present();
+        let pixels = shown.borrow().target.pixels().to_vec();
+        assert_eq!(&pixels[0..4], &[0xffffff, 0xffffff, 0x00ff00, 0x00ff00]);
+    }
+
+    #[test]
+    fn moving_a_layer() {
+        let (mut graphics, shown) = presented((4, 4));
+        graphics.draw_rect(ColorType::Foreground, (0, 0), (4, 4), None);
+        graphics.push_layer();
+        graphics.draw_rect(ColorType::Cursor, (1, 1), (2, 2), None);
+        graphics.move_layer(1, 0);
+        // drawing follows the layer that moved
+        graphics.draw_rect(ColorType::Cursor, (0, 0), (1, 1), None);
+        graphics.present();
+        assert!(shown
+            .borrow()
+            .target
+            .pixels()
+            .iter()
+            .all(|px| *px == 0xffffff));
+        graphics.move_layer(0, 1);
+        graphics.present();
+        let pixels = shown.borrow().target.pixels().to_vec();
+        assert_eq!(
+            (pixels[0], pixels[1], pixels[5]),
+            (0x00ff00, 0xffffff, 0x00ff00)
+        );
+    }
+
+    #[test]
+    fn dimming_reaches_through_empty_layers() {
+        let (mut graphics, shown) = presented((4, 4));
+        graphics.draw_rect(ColorType::Foreground, (0, 0), (4, 4), None);
+        graphics.push_layer();
+        graphics.draw_rect(ColorType::Cursor, (0, 0), (1, 1), None);
+        // the top layer has nothing drawn at (3, 3) but still dims it
+        graphics.set_layer_dim(1, 128);
+        graphics.present();
+        let pixels = shown.borrow().target.pixels().to_vec();
+        assert_eq!(pixels[15], 0x7f7f7f);
+        assert_eq!(pixels[0], 0x007f00);
+    }
+
+    const RED: Color = [255, 0, 0];
+    const BLUE: Color = [0, 0, 255];
+
+    /// Every pixel drawn on the active `Layer`, row after row
+    fn drawn(graphics: &Graphics) -> Vec<(usize, usize)> {
+        let (width, height) = graphics.target.resolution();
+        let layer = &graphics.layers[graphics.active_layer];
+        return (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .filter(|pos| layer.pixel(*pos).is_some())
+            .collect();
+    }
+
+    fn color_at(graphics: &Graphics, pos: (usize, usize)) -> Option<Color> {
+        return graphics.layers[graphics.active_layer].pixel(pos);
+    }
+
+    #[test]
+    fn line_includes_both_ends() {
+        let (mut g, _) = on_screen((10, 10));
+        g.draw_line((1, 2), (4, 2), RED);
+        assert_eq!(drawn(&g), [(1, 2), (2, 2), (3, 2), (4, 2)]);
+        assert_eq!(color_at(&g, (1, 2)), Some(RED));
+    }
+
+    #[test]
+    fn line_any_direction() {
+        let (mut forwards, _) = on_screen((10, 10));
+        forwards.draw_line((0, 0), (3, 3), RED);
+        assert_eq!(drawn(&forwards), [(0, 0), (1, 1), (2, 2), (3, 3)]);
+        let (mut backwards, _) = on_screen((10, 10));
+        backwards.draw_line((3, 3), (0, 0), RED);
+        assert_eq!(drawn(&backwards), drawn(&forwards));
+        // steep lines get a pixel on every row
+        let (mut steep, _) = on_screen((10, 10));
+        steep.draw_line((2, 0), (3, 5), RED);
+        let rows: Vec<usize> = drawn(&steep).iter().map(|(_, y)| *y).collect();
+        assert_eq!(rows, [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn drawing_is_clipped() {
+        let (mut g, _) = on_screen((10, 10));
+        g.push_clip(Rect::new((0, 0), (3, 10)));
+        g.draw_line((0, 1), (9, 1), RED);
+        g.pop_clip();
+        assert_eq!(drawn(&g), [(0, 1), (1, 1), (2, 1)]);
+        // off the edge of the screen is fine too
+        g.fill_circle((9, 9), 4, RED);
+        g.fill_rect(Rect::new((8, 8), (20, 20)), RED);
+    }
+
+    #[test]
+    fn outline_leaves_the_middle_alone() {
+        let (mut g, _) = on_screen((10, 10));
+        g.draw_outline(Rect::new((1, 1), (4, 3)), 1, RED);
+        #[rustfmt::skip]
+        let expected = [
+            (1, 1), (2, 1), (3, 1), (4, 1),
+            (1, 2),                 (4, 2),
+            (1, 3), (2, 3), (3, 3), (4, 3),
+        ];
+        assert_eq!(drawn(&g), expected);
+    }
+
+    #[test]
+    fn rounded_rect_cuts_corners() {
+        let (mut g, _) = on_screen((20, 20));
+        let rect = Rect::new((2, 2), (10, 8));
+        g.fill_rounded_rect(rect, 3, ColorType::Foreground);
+        assert_eq!(color_at(&g, (2, 2)), None);
+        assert_eq!(color_at(&g, (11, 9)), None);
+        assert_eq!(color_at(&g, (7, 2)), Some([255, 255, 255]));
+        assert_eq!(color_at(&g, (2, 6)), Some([255, 255, 255]));
+        // radius 0 is a plain rectangle
+        let (mut g, _) = on_screen((20, 20));
+        g.fill_rounded_rect(rect, 0, RED);
+        assert_eq!(drawn(&g).len(), 10 * 8);
+    }
+
+    #[test]
+    fn circles() {
+        let (mut g, _) = on_screen((20, 20));
+        g.fill_circle((10, 10), 0, RED);
+        assert_eq!(drawn(&g), [(10, 10)]);
+
+        let (mut g, _) = on_screen((20, 20));
+        g.fill_circle((10, 10), 4, RED);
+        for pos in [(10, 10), (6, 10), (14, 10), (10, 6), (10, 14)] {
+            assert_eq!(color_at(&g, pos), Some(RED));
+        }
+        assert_eq!(color_at(&g, (6, 6)), None);
+
+        let (mut ring, _) = on_screen((20, 20));
+        ring.draw_circle((10, 10), 4, 1, RED);
+        assert_eq!(color_at(&ring, (10, 10)), None);
+        assert_eq!(color_at(&ring, (14, 10)), Some(RED));
+        assert_eq!(color_at(&ring, (13, 10)), None);
+        // every pixel of the ring is part of the filled circle
+        assert!(drawn(&ring).iter().all(|pos| color_at(&g, *pos).is_some()));
+    }
+
+    #[test]
+    fn gradients() {
+        let (mut g, _) = on_screen((20, 20));
+        g.fill_gradient(
+            Rect::new((0, 0), (5, 2)),
+            RED,
+            BLUE,
+            GradientDirection::Horizontal,
+        );
+        assert_eq!(drawn(&g).len(), 10);
+        assert_eq!(color_at(&g, (0, 1)), Some(RED));
+        assert_eq!(color_at(&g, (4, 1)), Some(BLUE));
+        let [r, _, b] = color_at(&g, (2, 0)).expect("should be drawn");
+        assert!(r > 0 && r < 255 && b > 0 && b < 255);
+
+        let (mut g, _) = on_screen((20, 20));
+        g.fill_gradient(
+            Rect::new((0, 0), (5, 2)),
+            RED,
+            BLUE,
+            GradientDirection::Vertical,
+        );
+        assert_eq!(color_at(&g, (4, 0)), Some(RED));
+        assert_eq!(color_at(&g, (4, 1)), Some(BLUE));
+    }
+
+    #[test]
+    fn changing_resolution_starts_over() {
+        let (mut graphics, shown) = presented((4, 4));
+        graphics.draw_rect(ColorType::Foreground, (0, 0), (4, 4), None);
+        graphics.push_layer();
+        assert!(graphics.set_resolution((6, 3)));
+        assert_eq!(graphics.resolution(), (6, 3));
+        assert!(graphics
+            .layers
+            .iter()
+            .all(|layer| layer.resolution() == (6, 3)));
+        graphics.present();
+        // everything is pushed again, and nothing from before survived
+        assert_eq!(shown.borrow().blits, [Rect::new((0, 0), (6, 3))]);
+        assert_eq!(lit(&shown), 0);
+    }
+
+    #[test]
+    fn negotiating_keeps_the_mode_if_nothing_fits() {
+        let (mut graphics, _) = presented((4, 4));
+        let chosen = graphics.negotiate_resolution(ResolutionPolicy::Exact((8, 8)));
+        assert_eq!(chosen, (4, 4));
+        let chosen = graphics.negotiate_resolution(ResolutionPolicy::Largest);
+        assert_eq!(chosen, (4, 4));
+    }
+
+    #[test]
+    fn turned_sideways() {
+        let (mut graphics, shown) = presented((4, 2));
+        graphics.set_rotation(Rotation::Deg90);
+        assert_eq!(graphics.resolution(), (2, 4));
+        assert_eq!(graphics.rotation(), Rotation::Deg90);
+        graphics.draw_rect(ColorType::Foreground, (0, 0), (1, 1), None);
+        graphics.present();
+        // the whole panel gets pushed, in physical coordinates
+        assert_eq!(shown.borrow().blits, [Rect::new((0, 0), (4, 2))]);
+        // a quarter turn clockwise puts the top-left corner at the top-right
+        let pixels = shown.borrow().target.pixels().to_vec();
+        assert_eq!(pixels, [0, 0, 0, 0xffffff, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn capture_is_the_way_it_reads() {
+        let (mut graphics, _) = presented((3, 2));
+        graphics.set_rotation(Rotation::Deg90);
+        graphics.draw_rect(ColorType::Foreground, (0, 0), (1, 1), None);
+        // only what's been presented shows up
+        let before = graphics.capture();
+        graphics.present();
+        let after = graphics.capture();
+        assert_ne!(before, after);
+        // 2x3 once turned, bottom row first, padded to 8 bytes a row
+        let data = &after[after.len() - 24..];
+        assert_eq!(&data[16..22], &[255, 255, 255, 0, 0, 0]);
+        assert!(data[..16].iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn fonts_are_picked_by_name() {
+        let (mut graphics, _) = on_screen((16, 16));
+        let solid = |cell: (usize, usize)| -> Font {
+            let glyph = MonoBitmap::from_rows(cell.0, &vec![0xffff; cell.1]);
+            let metrics = vec![GlyphMetrics::from_ink(&glyph)];
+            return Font::new(cell, vec![glyph], metrics, BTreeMap::new(), 0);
+        };
+        graphics.add_font("solid", solid((2, 3)));
+        assert_eq!(graphics.char_dimensions(FontSize::P), (9, 11));
+        graphics.theme.font = String::from("solid");
+        assert_eq!(graphics.char_dimensions(FontSize::P), (2, 3));
+        graphics.write_char('x', (0, 0), FontSize::P, ColorType::Foreground);
+        assert_eq!(drawn(&graphics).len(), 6);
+
+        // same name, different font, so nothing cached can be reused
+        graphics.add_font("solid", solid((1, 1)));
+        graphics.write_char('x', (8, 8), FontSize::P, ColorType::Foreground);
+        assert_eq!(drawn(&graphics).len(), 7);
+        // names nobody added get the built-in font
+        graphics.theme.font = String::from("nope");
+        assert_eq!(graphics.char_dimensions(FontSize::P), (9, 11));
+    }
+
+    /// a 4 px cell with ink in the middle two columns, one row tall
+    fn narrow_font(resolution: (usize, usize)) -> Graphics<'static> {
+        let (mut graphics, _) = on_screen(resolution);
+        let glyph = MonoBitmap::from_rows(4, &[0b0110]);
+        let metrics = vec![GlyphMetrics::from_ink(&glyph)];
+        graphics.add_font(
+            "narrow",
+            Font::new((4, 1), vec![glyph], metrics, BTreeMap::new(), 0),
+        );
+        graphics.theme.font = String::from("narrow");
+        return graphics;
+    }
+
+    #[test]
+    fn proportional_text_moves_by_advance() {
+        let mut graphics = narrow_font((16, 4));
+        assert_eq!(graphics.char_advance('a', FontSize::P), 3);
+        assert_eq!(graphics.text_width("aa", FontSize::P), 6);
+
+        // the pen starts where the ink does, not where the cell does
+        let moved = graphics.write_text("aa", (5, 0), FontSize::P, ColorType::Foreground);
+        assert_eq!(moved, 6);
+        assert_eq!(drawn(&graphics), [(5, 0), (6, 0), (8, 0), (9, 0)]);
+    }
+
+    #[test]
+    fn draw_text_lines_up_by_options() {
+        let mut graphics = narrow_font((16, 4));
+        let centered = TextOptions {
+            halign: HAlign::Center,
+            valign: VAlign::Middle,
+            overflow: TextOverflow::Clip,
+        };
+        let rect = Rect::new((0, 0), (16, 4));
+        graphics.draw_text("aa", rect, centered, FontSize::P, ColorType::Foreground);
+        assert_eq!(drawn(&graphics), [(5, 1), (6, 1), (8, 1), (9, 1)]);
+
+        // the defaults start in the top left
+        let mut graphics = narrow_font((16, 4));
+        let rect = Rect::new((2, 0), (14, 4));
+        graphics.draw_text(
+            "aa",
+            rect,
+            TextOptions::default(),
+            FontSize::P,
+            ColorType::Foreground,
+        );
+        assert_eq!(drawn(&graphics), [(2, 0), (3, 0), (5, 0), (6, 0)]);
     }
 }
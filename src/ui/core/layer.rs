@@ -0,0 +1,134 @@
+use uefi::proto::console::gop::{PixelBitmask, PixelFormat};
+
+use no_std_compat::prelude::v1::vec;
+use no_std_compat::vec::Vec;
+
+use crate::graphics::Color;
+use crate::ui::core::rect::Rect;
+use crate::ui::core::render_target::{PixelEncoder, RenderTarget};
+
+/// Top byte of a pixel in a `Layer`. Set if something was drawn there.
+const OPAQUE: u32 = 0xff00_0000;
+
+/// An off-screen surface for one entry in the `UIStack`. `Graphics` composites
+/// every `Layer` from the bottom up to build a frame, so anything a `Layer`
+/// hasn't drawn on shows whatever is underneath it.
+///
+/// Pixels are stored like `PixelFormat::Bgr` with the reserved byte marking
+/// whether the pixel has been drawn. Blending onto a pixel that hasn't been
+/// drawn blends with black; there's no partial transparency between layers.
+pub struct Layer {
+    resolution: (usize, usize),
+    pixels: Vec<u32>,
+
+    /// How much of the background color gets blended over this `Layer` and
+    /// everything below it when compositing (out of 255).
+    pub dim: u8,
+
+    /// Everywhere anything has been drawn since the `Layer` was created or
+    /// cleared. Compositing can skip everything else.
+    pub extent: Rect,
+}
+
+impl Layer {
+    pub fn new(resolution: (usize, usize)) -> Layer {
+        Layer {
+            resolution: resolution,
+            pixels: vec![0; resolution.0 * resolution.1],
+            dim: 0,
+            extent: Rect::new((0, 0), (0, 0)),
+        }
+    }
+
+    /// How to encode colors for `write_pixel()`
+    pub fn encoder() -> PixelEncoder {
+        return PixelEncoder::new(PixelFormat::Bgr, None);
+    }
+
+    /// The color at `pos`, or `None` if nothing has been drawn there.
+    pub fn pixel(&self, pos: (usize, usize)) -> Option<Color> {
+        let value = self.pixels[(pos.1 * self.resolution.0) + pos.0];
+        if value & OPAQUE == 0 {
+            return None;
+        }
+        return Some(Layer::encoder().decode(value));
+    }
+
+    /// Set row `y` from `x0` up to `x1` to `value` in one go. Much faster than
+    /// `write_pixel()` over and over.
+    pub fn fill_span(&mut self, y: usize, x0: usize, x1: usize, value: u32) {
+        let row = y * self.resolution.0;
+        for px in self.pixels[row + x0..row + x1].iter_mut() {
+            *px = value | OPAQUE;
+        }
+    }
+
+    /// Forget everything drawn so the whole `Layer` is see-through again.
+    pub fn clear(&mut self) {
+        for px in self.pixels.iter_mut() {
+            *px = 0;
+        }
+        self.extent = Rect::new((0, 0), (0, 0));
+    }
+}
+
+impl RenderTarget for Layer {
+    fn resolution(&self) -> (usize, usize) {
+        return self.resolution;
+    }
+
+    fn stride(&self) -> usize {
+        return self.resolution.0;
+    }
+
+    fn pixel_format(&self) -> PixelFormat {
+        return PixelFormat::Bgr;
+    }
+
+    fn pixel_bitmask(&self) -> Option<PixelBitmask> {
+        return None;
+    }
+
+    /// Any resolution goes, so this is just the current one.
+    fn available_resolutions(&self) -> Vec<(usize, usize)> {
+        return vec![self.resolution];
+    }
+
+    /// Any resolution goes. Previous contents are thrown away.
+    fn set_resolution(&mut self, resolution: (usize, usize)) -> bool {
+        *self = Layer::new(resolution);
+        return true;
+    }
+
+    fn write_pixel(&mut self, pos: (usize, usize), value: u32) {
+        self.pixels[(pos.1 * self.resolution.0) + pos.0] = value | OPAQUE;
+    }
+
+    fn read_pixel(&mut self, pos: (usize, usize)) -> u32 {
+        return self.pixels[(pos.1 * self.resolution.0) + pos.0] & !OPAQUE;
+    }
+
+    fn fill(&mut self, dest: (usize, usize), dims: (usize, usize), value: u32) {
+        for y in dest.1..dest.1 + dims.1 {
+            self.fill_span(y, dest.0, dest.0 + dims.0, value);
+        }
+        self.extent = self.extent.union(&Rect::new(dest, dims));
+    }
+
+    fn blit(
+        &mut self,
+        buffer: &[u32],
+        buffer_stride: usize,
+        src: (usize, usize),
+        dest: (usize, usize),
+        dims: (usize, usize),
+    ) {
+        for y in 0..dims.1 {
+            for x in 0..dims.0 {
+                let value = buffer[((src.1 + y) * buffer_stride) + src.0 + x];
+                self.write_pixel((dest.0 + x, dest.1 + y), value);
+            }
+        }
+        self.extent = self.extent.union(&Rect::new(dest, dims));
+    }
+}
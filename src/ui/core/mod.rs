@@ -1,8 +1,18 @@
 use no_std_compat::string::String;
 
 pub mod application;
-pub mod bmp;
+pub mod bitmap;
+pub mod codec;
+pub mod display;
+pub mod glyph_cache;
 pub mod graphics;
+pub mod layer;
+pub mod rect;
+pub mod render_target;
+pub mod resolution;
+pub mod rotation;
+pub mod scale;
+pub mod text;
 pub mod widget;
 
 pub mod font;
@@ -0,0 +1,144 @@
+use no_std_compat::cmp;
+
+/// A rectangle of pixels. `x` and `y` are the top-left corner, the right and
+/// bottom edges are exclusive.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    /// top_left: (x, y) coordinates of the top-left corner (in px)
+    /// dimensions: (x, y) dimensions in px
+    pub const fn new(top_left: (usize, usize), dimensions: (usize, usize)) -> Rect {
+        Rect {
+            x: top_left.0,
+            y: top_left.1,
+            width: dimensions.0,
+            height: dimensions.1,
+        }
+    }
+
+    pub fn top_left(&self) -> (usize, usize) {
+        return (self.x, self.y);
+    }
+
+    pub fn dimensions(&self) -> (usize, usize) {
+        return (self.width, self.height);
+    }
+
+    /// First column to the right of the `Rect`
+    pub fn right(&self) -> usize {
+        return self.x + self.width;
+    }
+
+    /// First row below the `Rect`
+    pub fn bottom(&self) -> usize {
+        return self.y + self.height;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.width == 0 || self.height == 0;
+    }
+
+    /// Whether the two `Rect`s share at least one pixel
+    pub fn intersects(&self, other: &Rect) -> bool {
+        return self.intersection(other).is_some();
+    }
+
+    /// The pixels both `Rect`s have in common, if there are any
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let x = cmp::max(self.x, other.x);
+        let y = cmp::max(self.y, other.y);
+        let right = cmp::min(self.right(), other.right());
+        let bottom = cmp::min(self.bottom(), other.bottom());
+        if x >= right || y >= bottom {
+            return None;
+        }
+        return Some(Rect::new((x, y), (right - x, bottom - y)));
+    }
+
+    /// Stretch the `Rect` by however much a `from` sized screen has to stretch
+    /// to become a `to` sized one. Handy when the resolution changes.
+    pub fn scale(&self, from: (usize, usize), to: (usize, usize)) -> Rect {
+        if from.0 == 0 || from.1 == 0 {
+            return *self;
+        }
+        let x = (self.x * to.0) / from.0;
+        let y = (self.y * to.1) / from.1;
+        let right = (self.right() * to.0) / from.0;
+        let bottom = (self.bottom() * to.1) / from.1;
+        return Rect::new((x, y), (right - x, bottom - y));
+    }
+
+    /// The smallest `Rect` that covers both `Rect`s
+    pub fn union(&self, other: &Rect) -> Rect {
+        if self.is_empty() {
+            return *other;
+        } else if other.is_empty() {
+            return *self;
+        }
+        let x = cmp::min(self.x, other.x);
+        let y = cmp::min(self.y, other.y);
+        let right = cmp::max(self.right(), other.right());
+        let bottom = cmp::max(self.bottom(), other.bottom());
+        return Rect::new((x, y), (right - x, bottom - y));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edges_are_exclusive() {
+        let rect = Rect::new((2, 3), (4, 5));
+        assert_eq!(rect.right(), 6);
+        assert_eq!(rect.bottom(), 8);
+        assert!(!rect.is_empty());
+        assert!(Rect::new((2, 3), (0, 5)).is_empty());
+        assert!(Rect::new((2, 3), (4, 0)).is_empty());
+    }
+
+    #[test]
+    fn intersection() {
+        let a = Rect::new((0, 0), (10, 10));
+        let b = Rect::new((5, 8), (10, 10));
+        assert_eq!(a.intersection(&b), Some(Rect::new((5, 8), (5, 2))));
+        assert_eq!(b.intersection(&a), a.intersection(&b));
+        assert!(a.intersects(&b));
+        // touching edges don't share a pixel
+        let c = Rect::new((10, 0), (5, 5));
+        assert_eq!(a.intersection(&c), None);
+        assert!(!a.intersects(&c));
+        // nothing intersects an empty rect
+        assert_eq!(a.intersection(&Rect::new((5, 5), (0, 0))), None);
+    }
+
+    #[test]
+    fn union() {
+        let a = Rect::new((0, 0), (2, 2));
+        let b = Rect::new((5, 1), (1, 4));
+        assert_eq!(a.union(&b), Rect::new((0, 0), (6, 5)));
+        assert_eq!(b.union(&a), a.union(&b));
+        // empty rects don't drag the union towards them
+        let empty = Rect::new((100, 100), (0, 0));
+        assert_eq!(a.union(&empty), a);
+        assert_eq!(empty.union(&a), a);
+    }
+
+    #[test]
+    fn scale() {
+        let rect = Rect::new((10, 20), (30, 40));
+        assert_eq!(
+            rect.scale((100, 100), (200, 50)),
+            Rect::new((20, 10), (60, 20))
+        );
+        // same size or a zero sized screen leave it alone
+        assert_eq!(rect.scale((100, 100), (100, 100)), rect);
+        assert_eq!(rect.scale((0, 100), (200, 50)), rect);
+    }
+}
@@ -0,0 +1,516 @@
+use uefi::proto::console::gop::{
+    BltOp, BltPixel, BltRegion, GraphicsOutput, ModeInfo, PixelBitmask, PixelFormat,
+};
+use uefi::ResultExt;
+
+use no_std_compat::prelude::v1::vec;
+use no_std_compat::slice;
+use no_std_compat::vec::Vec;
+
+use crate::graphics::Color;
+
+/// Something `Graphics` can put pixels on. Real hardware gets a `GopTarget`,
+/// anything else (tests, off-screen drawing) can use a `MemoryTarget`.
+///
+/// Pixel values handed to `write_pixel()` and `blit()` are already encoded in
+/// the target's `pixel_format()`; see `PixelEncoder`.
+pub trait RenderTarget {
+    /// (x, y) size of the visible area in pixels
+    fn resolution(&self) -> (usize, usize);
+
+    /// Pixels per scanline. Can be bigger than the visible width.
+    fn stride(&self) -> usize;
+
+    /// How `write_pixel()` values are laid out
+    fn pixel_format(&self) -> PixelFormat;
+
+    /// Which bits belong to which channel. Only for `PixelFormat::Bitmask`.
+    fn pixel_bitmask(&self) -> Option<PixelBitmask>;
+
+    /// Every resolution `set_resolution()` will accept
+    fn available_resolutions(&self) -> Vec<(usize, usize)>;
+
+    /// Switch to a different resolution. Returns false if the target can't.
+    fn set_resolution(&mut self, resolution: (usize, usize)) -> bool;
+
+    /// Write a single pixel. `pos` is (x, y) in px.
+    fn write_pixel(&mut self, pos: (usize, usize), value: u32);
+
+    /// Read back a single pixel. `pos` is (x, y) in px.
+    fn read_pixel(&mut self, pos: (usize, usize)) -> u32;
+
+    /// Set a `dims` sized block with its top-left corner at `dest` to `value`.
+    /// Targets that can do better than `write_pixel()` over and over should.
+    fn fill(&mut self, dest: (usize, usize), dims: (usize, usize), value: u32) {
+        for y in dest.1..dest.1 + dims.1 {
+            for x in dest.0..dest.0 + dims.0 {
+                self.write_pixel((x, y), value);
+            }
+        }
+    }
+
+    /// Copy a `dims` sized block starting at `src` in `buffer` (rows of
+    /// `buffer_stride` pixels) onto the target with its top-left corner at `dest`.
+    fn blit(
+        &mut self,
+        buffer: &[u32],
+        buffer_stride: usize,
+        src: (usize, usize),
+        dest: (usize, usize),
+        dims: (usize, usize),
+    );
+}
+
+/// `RenderTarget` backed by the UEFI `GraphicsOutput` protocol. Pixels get
+/// written straight into the framebuffer.
+///
+/// Some modes (`PixelFormat::BltOnly`) don't have a framebuffer we can touch.
+/// In those everything goes through `GraphicsOutput::blt()` instead and pixel
+/// values are expected in `BltPixel`'s layout, same as `PixelFormat::Bgr`.
+pub struct GopTarget<'a> {
+    gop: &'a mut GraphicsOutput<'a>,
+    // asking the firmware for this on every pixel adds up
+    info: ModeInfo,
+}
+
+impl<'a> GopTarget<'a> {
+    pub fn new(gop: &'a mut GraphicsOutput<'a>) -> GopTarget<'a> {
+        let info = gop.current_mode_info();
+        GopTarget {
+            gop: gop,
+            info: info,
+        }
+    }
+}
+
+impl<'a> RenderTarget for GopTarget<'a> {
+    fn resolution(&self) -> (usize, usize) {
+        return self.info.resolution();
+    }
+
+    fn stride(&self) -> usize {
+        return self.info.stride();
+    }
+
+    fn pixel_format(&self) -> PixelFormat {
+        return self.info.pixel_format();
+    }
+
+    fn pixel_bitmask(&self) -> Option<PixelBitmask> {
+        return self.info.pixel_bitmask();
+    }
+
+    /// Resolutions of every mode the firmware offers, without duplicates
+    /// (some modes only differ in pixel format).
+    fn available_resolutions(&self) -> Vec<(usize, usize)> {
+        let mut resolutions: Vec<(usize, usize)> = Vec::new();
+        for mode in self.gop.modes() {
+            let resolution = mode.expect("failed to get mode").info().resolution();
+            if !resolutions.contains(&resolution) {
+                resolutions.push(resolution);
+            }
+        }
+        return resolutions;
+    }
+
+    /// Set the resolution if the specified value is among the list of
+    /// available modes.
+    fn set_resolution(&mut self, resolution: (usize, usize)) -> bool {
+        let mode = self
+            .gop
+            .modes()
+            .map(|mode| mode.expect("failed to get mode"))
+            .find(|mode| mode.info().resolution() == resolution);
+        if let Some(value) = mode {
+            self.gop
+                .set_mode(&value)
+                .expect_success("failed to set mode");
+            self.info = self.gop.current_mode_info();
+            return true;
+        }
+        return false;
+    }
+
+    /// Slow, the framebuffer gets looked up every time. Anything drawing more
+    /// than a pixel should use `fill()` or `blit()`.
+    fn write_pixel(&mut self, pos: (usize, usize), value: u32) {
+        self.fill(pos, (1, 1), value);
+    }
+
+    fn fill(&mut self, dest: (usize, usize), dims: (usize, usize), value: u32) {
+        if self.info.pixel_format() == PixelFormat::BltOnly {
+            self.gop
+                .blt(BltOp::VideoFill {
+                    color: BltPixel::from(value),
+                    dest: dest,
+                    dims: dims,
+                })
+                .expect_success("failed to blt");
+            return;
+        }
+
+        // stride is pixels per scanline
+        // y * stride == row of pixels, add x for column
+        let stride = self.info.stride();
+        let mut fb = self.gop.frame_buffer();
+        for y in dest.1..dest.1 + dims.1 {
+            for x in dest.0..dest.0 + dims.0 {
+                // resolution.x * resolution.y * 4 == fb.size()
+                // i don't think we get an alpha byte (rgba) so it must be an alignment thing
+                unsafe {
+                    fb.write_value(4 * ((y * stride) + x), value);
+                }
+            }
+        }
+    }
+
+    /// Reading video memory is slow, `Graphics` only ever reads from its back
+    /// buffer. Not supported in `PixelFormat::BltOnly` modes.
+    fn read_pixel(&mut self, pos: (usize, usize)) -> u32 {
+        assert!(
+            self.info.pixel_format() != PixelFormat::BltOnly,
+            "can't read pixels without a framebuffer"
+        );
+        let idx = (pos.1 * self.info.stride()) + pos.0;
+        unsafe {
+            return self.gop.frame_buffer().read_value(4 * idx);
+        }
+    }
+
+    fn blit(
+        &mut self,
+        buffer: &[u32],
+        buffer_stride: usize,
+        src: (usize, usize),
+        dest: (usize, usize),
+        dims: (usize, usize),
+    ) {
+        let format = self.info.pixel_format();
+        if format == PixelFormat::Rgb || format == PixelFormat::Bitmask {
+            // blt() only takes BGR. our buffer is already in the framebuffer's
+            // format so just copy it in
+            let stride = self.info.stride();
+            let mut fb = self.gop.frame_buffer();
+            for y in 0..dims.1 {
+                for x in 0..dims.0 {
+                    let value = buffer[((src.1 + y) * buffer_stride) + src.0 + x];
+                    let idx = ((dest.1 + y) * stride) + dest.0 + x;
+                    unsafe {
+                        fb.write_value(4 * idx, value);
+                    }
+                }
+            }
+            return;
+        }
+
+        // BltPixel is a #[repr(C)] struct of 4 bytes in the same order as our
+        // little-endian 0x00RRGGBB values so we can hand the firmware our buffer
+        let buffer =
+            unsafe { slice::from_raw_parts(buffer.as_ptr() as *const BltPixel, buffer.len()) };
+        self.gop
+            .blt(BltOp::BufferToVideo {
+                buffer: buffer,
+                src: BltRegion::SubRectangle {
+                    coords: src,
+                    px_stride: buffer_stride,
+                },
+                dest: dest,
+                dims: dims,
+            })
+            .expect_success("failed to blt");
+    }
+}
+
+/// `RenderTarget` that's just a `Vec<u32>` in memory, with no padding between
+/// rows. Pixels are laid out the same way as `BltPixel` (`PixelFormat::Bgr`)
+/// unless some other format is asked for.
+pub struct MemoryTarget {
+    resolution: (usize, usize),
+    format: PixelFormat,
+    bitmask: Option<PixelBitmask>,
+    pixels: Vec<u32>,
+}
+
+impl MemoryTarget {
+    /// `PixelFormat::Bgr`, which is what tests draw into
+    #[cfg(test)]
+    pub fn new(resolution: (usize, usize)) -> MemoryTarget {
+        return MemoryTarget::with_format(resolution, PixelFormat::Bgr, None);
+    }
+
+    /// Make a `MemoryTarget` that stores pixels the same way some framebuffer
+    /// does. `bitmask` is only used for `PixelFormat::Bitmask`.
+    pub fn with_format(
+        resolution: (usize, usize),
+        format: PixelFormat,
+        bitmask: Option<PixelBitmask>,
+    ) -> MemoryTarget {
+        MemoryTarget {
+            resolution: resolution,
+            format: format,
+            bitmask: bitmask,
+            pixels: vec![0; resolution.0 * resolution.1],
+        }
+    }
+
+    /// Everything drawn so far, row after row.
+    pub fn pixels(&self) -> &[u32] {
+        return &self.pixels;
+    }
+}
+
+impl RenderTarget for MemoryTarget {
+    fn resolution(&self) -> (usize, usize) {
+        return self.resolution;
+    }
+
+    fn stride(&self) -> usize {
+        return self.resolution.0;
+    }
+
+    fn pixel_format(&self) -> PixelFormat {
+        return self.format;
+    }
+
+    fn pixel_bitmask(&self) -> Option<PixelBitmask> {
+        return self.bitmask;
+    }
+
+    /// Any resolution goes, so this is just the current one.
+    fn available_resolutions(&self) -> Vec<(usize, usize)> {
+        return vec![self.resolution];
+    }
+
+    /// Any resolution goes. Previous contents are thrown away.
+    fn set_resolution(&mut self, resolution: (usize, usize)) -> bool {
+        self.resolution = resolution;
+        self.pixels = vec![0; resolution.0 * resolution.1];
+        return true;
+    }
+
+    fn write_pixel(&mut self, pos: (usize, usize), value: u32) {
+        let idx = (pos.1 * self.resolution.0) + pos.0;
+        self.pixels[idx] = value;
+    }
+
+    fn read_pixel(&mut self, pos: (usize, usize)) -> u32 {
+        return self.pixels[(pos.1 * self.resolution.0) + pos.0];
+    }
+
+    fn fill(&mut self, dest: (usize, usize), dims: (usize, usize), value: u32) {
+        for y in dest.1..dest.1 + dims.1 {
+            let row = (y * self.resolution.0) + dest.0;
+            for px in self.pixels[row..row + dims.0].iter_mut() {
+                *px = value;
+            }
+        }
+    }
+
+    fn blit(
+        &mut self,
+        buffer: &[u32],
+        buffer_stride: usize,
+        src: (usize, usize),
+        dest: (usize, usize),
+        dims: (usize, usize),
+    ) {
+        for y in 0..dims.1 {
+            let from = ((src.1 + y) * buffer_stride) + src.0;
+            let to = ((dest.1 + y) * self.resolution.0) + dest.0;
+            self.pixels[to..to + dims.0].copy_from_slice(&buffer[from..from + dims.0]);
+        }
+    }
+}
+
+/// Turns a `Color` into the `u32` a framebuffer with a given `PixelFormat`
+/// expects. Every pixel `Graphics` writes goes through here so themes look the
+/// same no matter what order the firmware wants its bytes in.
+#[derive(Clone, Copy)]
+pub struct PixelEncoder {
+    format: PixelFormat,
+    bitmask: Option<PixelBitmask>,
+}
+
+impl PixelEncoder {
+    pub fn new(format: PixelFormat, bitmask: Option<PixelBitmask>) -> PixelEncoder {
+        PixelEncoder {
+            format: format,
+            bitmask: bitmask,
+        }
+    }
+
+    /// Encoder matching whatever `target` stores
+    pub fn for_target(target: &dyn RenderTarget) -> PixelEncoder {
+        return PixelEncoder::new(target.pixel_format(), target.pixel_bitmask());
+    }
+
+    pub fn encode(&self, color: Color) -> u32 {
+        let [r, g, b] = color;
+        match self.format {
+            PixelFormat::Rgb => {
+                return u32::from_le_bytes([r, g, b, 0]);
+            }
+            PixelFormat::Bitmask => {
+                let mask = self.bitmask.expect("bitmask format without a bitmask");
+                return PixelEncoder::fit_to_mask(r, mask.red)
+                    | PixelEncoder::fit_to_mask(g, mask.green)
+                    | PixelEncoder::fit_to_mask(b, mask.blue);
+            }
+            // BltPixel's layout
+            _ => {
+                return u32::from_le_bytes([b, g, r, 0]);
+            }
+        }
+    }
+
+    /// The reverse of `encode()`
+    pub fn decode(&self, value: u32) -> Color {
+        match self.format {
+            PixelFormat::Rgb => {
+                let [r, g, b, _] = value.to_le_bytes();
+                return [r, g, b];
+            }
+            PixelFormat::Bitmask => {
+                let mask = self.bitmask.expect("bitmask format without a bitmask");
+                return [
+                    PixelEncoder::extract_from_mask(value, mask.red),
+                    PixelEncoder::extract_from_mask(value, mask.green),
+                    PixelEncoder::extract_from_mask(value, mask.blue),
+                ];
+            }
+            _ => {
+                let [b, g, r, _] = value.to_le_bytes();
+                return [r, g, b];
+            }
+        }
+    }
+
+    /// Scale an 8-bit channel to however many bits `mask` has and shift it
+    /// into place. Assumes the mask's bits are contiguous, which they'd better be.
+    fn fit_to_mask(channel: u8, mask: u32) -> u32 {
+        if mask == 0 {
+            return 0;
+        }
+        let shift = mask.trailing_zeros();
+        let max = u64::from(mask >> shift);
+        let value = ((u64::from(channel) * max) + 127) / 255;
+        return ((value as u32) << shift) & mask;
+    }
+
+    /// Pull a channel out from under `mask` and scale it back up to 8 bits.
+    fn extract_from_mask(value: u32, mask: u32) -> u8 {
+        if mask == 0 {
+            return 0;
+        }
+        let shift = mask.trailing_zeros();
+        let max = u64::from(mask >> shift);
+        let channel = u64::from((value & mask) >> shift);
+        return (((channel * 255) + (max / 2)) / max) as u8;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_target_starts_black() {
+        let target = MemoryTarget::new((4, 3));
+        assert_eq!(target.pixels(), &[0; 12][..]);
+    }
+
+    #[test]
+    fn memory_target_write_and_read_pixel() {
+        let mut target = MemoryTarget::new((4, 3));
+        target.write_pixel((2, 1), 0x123456);
+        assert_eq!(target.read_pixel((2, 1)), 0x123456);
+        assert_eq!(target.pixels()[6], 0x123456);
+        assert_eq!(target.pixels().iter().filter(|px| **px != 0).count(), 1);
+    }
+
+    #[test]
+    fn memory_target_fill() {
+        let mut target = MemoryTarget::new((4, 3));
+        target.fill((1, 1), (2, 2), 7);
+        #[rustfmt::skip]
+        let expected = [
+            0, 0, 0, 0,
+            0, 7, 7, 0,
+            0, 7, 7, 0,
+        ];
+        assert_eq!(target.pixels(), &expected[..]);
+    }
+
+    #[test]
+    fn memory_target_blit_part_of_a_buffer() {
+        let mut target = MemoryTarget::new((4, 3));
+        #[rustfmt::skip]
+        let buffer = [
+            1, 2, 3,
+            4, 5, 6,
+        ];
+        target.blit(&buffer, 3, (1, 0), (0, 1), (2, 2));
+        #[rustfmt::skip]
+        let expected = [
+            0, 0, 0, 0,
+            2, 3, 0, 0,
+            5, 6, 0, 0,
+        ];
+        assert_eq!(target.pixels(), &expected[..]);
+    }
+
+    #[test]
+    fn memory_target_set_resolution_clears() {
+        let mut target = MemoryTarget::new((2, 2));
+        target.fill((0, 0), (2, 2), 1);
+        assert!(target.set_resolution((3, 1)));
+        assert_eq!(target.resolution(), (3, 1));
+        assert_eq!(target.pixels(), &[0, 0, 0][..]);
+    }
+
+    #[test]
+    fn encoder_matches_blt_pixel_layout() {
+        let encoder = PixelEncoder::new(PixelFormat::Bgr, None);
+        assert_eq!(encoder.encode([0x12, 0x34, 0x56]), 0x123456);
+        let encoder = PixelEncoder::new(PixelFormat::Rgb, None);
+        assert_eq!(encoder.encode([0x12, 0x34, 0x56]), 0x563412);
+    }
+
+    #[test]
+    fn encoder_fits_bitmasks() {
+        // 5-6-5
+        let bitmask = PixelBitmask {
+            red: 0xf800,
+            green: 0x07e0,
+            blue: 0x001f,
+            reserved: 0,
+        };
+        let encoder = PixelEncoder::new(PixelFormat::Bitmask, Some(bitmask));
+        assert_eq!(encoder.encode([255, 255, 255]), 0xffff);
+        assert_eq!(encoder.encode([255, 0, 0]), 0xf800);
+        assert_eq!(encoder.encode([0, 255, 0]), 0x07e0);
+        assert_eq!(encoder.encode([0, 0, 255]), 0x001f);
+        // 128/255 of 31 rounds to 16
+        assert_eq!(encoder.encode([128, 0, 0]), 16 << 11);
+    }
+
+    #[test]
+    fn encoder_round_trips() {
+        let bitmask = PixelBitmask {
+            red: 0xf800,
+            green: 0x07e0,
+            blue: 0x001f,
+            reserved: 0,
+        };
+        for (format, bitmask) in [
+            (PixelFormat::Bgr, None),
+            (PixelFormat::Rgb, None),
+            (PixelFormat::Bitmask, Some(bitmask)),
+        ] {
+            let encoder = PixelEncoder::new(format, bitmask);
+            for color in [[0, 0, 0], [255, 255, 255], [255, 0, 0], [0, 255, 0]] {
+                assert_eq!(encoder.decode(encoder.encode(color)), color);
+            }
+        }
+    }
+}
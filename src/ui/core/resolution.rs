@@ -0,0 +1,124 @@
+use no_std_compat::cmp;
+
+/// How to pick a resolution out of the modes the firmware offers. Real
+/// hardware rarely has exactly the mode you'd like so anything but `Exact`
+/// will settle for something else.
+#[derive(Clone, Copy, Debug)]
+#[allow(dead_code)]
+pub enum ResolutionPolicy {
+    /// This resolution or nothing
+    Exact((usize, usize)),
+    /// Whatever's nearest in width and height
+    Closest((usize, usize)),
+    /// The biggest mode there is
+    Largest,
+    /// The biggest mode with this (width, height) aspect ratio, or the nearest
+    /// ratio if none match
+    Aspect((usize, usize)),
+}
+
+impl ResolutionPolicy {
+    /// Choose a resolution out of `modes`. `None` if nothing fits the policy.
+    pub fn choose(&self, modes: &[(usize, usize)]) -> Option<(usize, usize)> {
+        match *self {
+            ResolutionPolicy::Exact(resolution) => {
+                return modes.iter().find(|mode| **mode == resolution).copied();
+            }
+            ResolutionPolicy::Closest((width, height)) => {
+                // ties go to the bigger mode
+                return modes
+                    .iter()
+                    .min_by_key(|(w, h)| {
+                        let distance = abs_diff(*w, width) + abs_diff(*h, height);
+                        (distance, cmp::Reverse(w * h))
+                    })
+                    .copied();
+            }
+            ResolutionPolicy::Largest => {
+                return modes.iter().max_by_key(|(w, h)| (w * h, *w)).copied();
+            }
+            ResolutionPolicy::Aspect((width, height)) => {
+                // compare w/h against width/height without dividing:
+                // |w * height - h * width| / (h * height) is how far off it is
+                return modes
+                    .iter()
+                    .filter(|(_, h)| *h > 0)
+                    .min_by(|(w1, h1), (w2, h2)| {
+                        let off1 = abs_diff(w1 * height, h1 * width) * h2;
+                        let off2 = abs_diff(w2 * height, h2 * width) * h1;
+                        off1.cmp(&off2).then((w2 * h2).cmp(&(w1 * h1)))
+                    })
+                    .copied();
+            }
+        }
+    }
+}
+
+fn abs_diff(a: usize, b: usize) -> usize {
+    if a > b {
+        return a - b;
+    }
+    return b - a;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODES: [(usize, usize); 5] = [
+        (640, 480),
+        (800, 600),
+        (1024, 768),
+        (1280, 720),
+        (1920, 1080),
+    ];
+
+    #[test]
+    fn exact() {
+        let policy = ResolutionPolicy::Exact((1024, 768));
+        assert_eq!(policy.choose(&MODES), Some((1024, 768)));
+        let policy = ResolutionPolicy::Exact((1024, 600));
+        assert_eq!(policy.choose(&MODES), None);
+    }
+
+    #[test]
+    fn closest() {
+        let policy = ResolutionPolicy::Closest((1024, 600));
+        assert_eq!(policy.choose(&MODES), Some((1024, 768)));
+        let policy = ResolutionPolicy::Closest((1280, 720));
+        assert_eq!(policy.choose(&MODES), Some((1280, 720)));
+        // (720, 540) is 80 + 60 from both of these, the bigger one wins
+        let policy = ResolutionPolicy::Closest((720, 540));
+        assert_eq!(policy.choose(&MODES), Some((800, 600)));
+    }
+
+    #[test]
+    fn largest() {
+        assert_eq!(ResolutionPolicy::Largest.choose(&MODES), Some((1920, 1080)));
+    }
+
+    #[test]
+    fn aspect() {
+        // biggest 16:9
+        let policy = ResolutionPolicy::Aspect((16, 9));
+        assert_eq!(policy.choose(&MODES), Some((1920, 1080)));
+        // biggest 4:3
+        let policy = ResolutionPolicy::Aspect((4, 3));
+        assert_eq!(policy.choose(&MODES), Some((1024, 768)));
+        // nothing is 16:10, 16:9 is nearest
+        let policy = ResolutionPolicy::Aspect((16, 10));
+        assert_eq!(policy.choose(&MODES), Some((1920, 1080)));
+    }
+
+    #[test]
+    fn no_modes() {
+        for policy in [
+            ResolutionPolicy::Exact((640, 480)),
+            ResolutionPolicy::Closest((640, 480)),
+            ResolutionPolicy::Largest,
+            ResolutionPolicy::Aspect((4, 3)),
+        ] {
+            assert_eq!(policy.choose(&[]), None);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::ui::core::rect::Rect;
+
+/// How far the picture is turned clockwise before it reaches the screen. For
+/// panels mounted sideways or upside down.
+///
+/// Everything `Graphics` hands out (resolution, clip, damage) is in logical
+/// coordinates, i.e. the way the picture looks to someone reading the panel.
+/// Only `Graphics::present()` deals in physical framebuffer coordinates.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Rotation {
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+impl Rotation {
+    /// Whether width and height trade places
+    fn is_sideways(&self) -> bool {
+        return *self == Rotation::Deg90 || *self == Rotation::Deg270;
+    }
+
+    /// A quarter turn further clockwise, coming back around to `Deg0`
+    pub fn clockwise(&self) -> Rotation {
+        return match self {
+            Rotation::Deg0 => Rotation::Deg90,
+            Rotation::Deg90 => Rotation::Deg180,
+            Rotation::Deg180 => Rotation::Deg270,
+            Rotation::Deg270 => Rotation::Deg0,
+        };
+    }
+
+    /// Size of the picture widgets lay out against on a `physical` sized
+    /// screen. Works the other way around too.
+    pub fn logical_size(&self, physical: (usize, usize)) -> (usize, usize) {
+        if self.is_sideways() {
+            return (physical.1, physical.0);
+        }
+        return physical;
+    }
+
+    /// Where logical pixel `pos` ends up on the screen. `logical` is the size
+    /// of the logical picture.
+    pub fn to_physical(&self, pos: (usize, usize), logical: (usize, usize)) -> (usize, usize) {
+        let (x, y) = pos;
+        let (width, height) = logical;
+        return match self {
+            Rotation::Deg0 => (x, y),
+            Rotation::Deg90 => (height - 1 - y, x),
+            Rotation::Deg180 => (width - 1 - x, height - 1 - y),
+            Rotation::Deg270 => (y, width - 1 - x),
+        };
+    }
+
+    /// The logical pixel that shows up at physical pixel `pos`. The reverse of
+    /// `to_physical()`.
+    pub fn to_logical(&self, pos: (usize, usize), logical: (usize, usize)) -> (usize, usize) {
+        let (x, y) = pos;
+        let (width, height) = logical;
+        return match self {
+            Rotation::Deg0 => (x, y),
+            Rotation::Deg90 => (y, height - 1 - x),
+            Rotation::Deg180 => (width - 1 - x, height - 1 - y),
+            Rotation::Deg270 => (width - 1 - y, x),
+        };
+    }
+
+    /// The physical pixels a logical `rect` covers
+    pub fn rect_to_physical(&self, rect: Rect, logical: (usize, usize)) -> Rect {
+        if rect.is_empty() {
+            return rect;
+        }
+        let a = self.to_physical(rect.top_left(), logical);
+        let b = self.to_physical((rect.right() - 1, rect.bottom() - 1), logical);
+        let top_left = (a.0.min(b.0), a.1.min(b.1));
+        let bottom_right = (a.0.max(b.0), a.1.max(b.1));
+        return Rect::new(
+            top_left,
+            (
+                bottom_right.0 - top_left.0 + 1,
+                bottom_right.1 - top_left.1 + 1,
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [Rotation; 4] = [
+        Rotation::Deg0,
+        Rotation::Deg90,
+        Rotation::Deg180,
+        Rotation::Deg270,
+    ];
+
+    #[test]
+    fn logical_size() {
+        assert_eq!(Rotation::Deg0.logical_size((800, 600)), (800, 600));
+        assert_eq!(Rotation::Deg90.logical_size((800, 600)), (600, 800));
+        assert_eq!(Rotation::Deg180.logical_size((800, 600)), (800, 600));
+        assert_eq!(Rotation::Deg270.logical_size((800, 600)), (600, 800));
+    }
+
+    #[test]
+    fn corners() {
+        // the logical top-left corner of a 4x3 picture
+        let logical = (4, 3);
+        assert_eq!(Rotation::Deg0.to_physical((0, 0), logical), (0, 0));
+        // turned clockwise it ends up top-right on a 3x4 screen
+        assert_eq!(Rotation::Deg90.to_physical((0, 0), logical), (2, 0));
+        assert_eq!(Rotation::Deg180.to_physical((0, 0), logical), (3, 2));
+        assert_eq!(Rotation::Deg270.to_physical((0, 0), logical), (0, 3));
+    }
+
+    #[test]
+    fn to_logical_undoes_to_physical() {
+        let logical = (5, 3);
+        for rotation in ALL {
+            for y in 0..logical.1 {
+                for x in 0..logical.0 {
+                    let physical = rotation.to_physical((x, y), logical);
+                    let size = rotation.logical_size(logical);
+                    assert!(physical.0 < size.0 && physical.1 < size.1);
+                    assert_eq!(rotation.to_logical(physical, logical), (x, y));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rect_to_physical() {
+        let logical = (10, 6);
+        let rect = Rect::new((1, 2), (3, 4));
+        assert_eq!(Rotation::Deg0.rect_to_physical(rect, logical), rect);
+        assert_eq!(
+            Rotation::Deg90.rect_to_physical(rect, logical),
+            Rect::new((0, 1), (4, 3))
+        );
+        assert_eq!(
+            Rotation::Deg180.rect_to_physical(rect, logical),
+            Rect::new((6, 0), (3, 4))
+        );
+        assert_eq!(
+            Rotation::Deg270.rect_to_physical(rect, logical),
+            Rect::new((2, 6), (4, 3))
+        );
+        let empty = Rect::new((1, 2), (0, 4));
+        assert_eq!(Rotation::Deg90.rect_to_physical(empty, logical), empty);
+    }
+
+    #[test]
+    fn clockwise_comes_back_around() {
+        let mut rotation = Rotation::Deg0;
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            rotation = rotation.clockwise();
+            seen.push(rotation);
+        }
+        use Rotation::*;
+        assert_eq!(seen, [Deg90, Deg180, Deg270, Deg0]);
+    }
+}
@@ -0,0 +1,74 @@
+/// How many steps there are between one whole factor and the next
+const ONE: usize = 256;
+
+/// A scale factor that doesn't have to be a whole number, e.g. 1.5x for text
+/// that's too small at 1x and too big at 2x. Stored as fixed point in 1/256ths
+/// since floats don't get much help without `std`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Scale(usize);
+
+impl Scale {
+    /// Exactly `factor` times
+    pub const fn whole(factor: usize) -> Scale {
+        Scale(factor * ONE)
+    }
+
+    /// `numerator / denominator` times, rounded to the nearest 1/256th.
+    /// `Scale::ratio(3, 2)` is 1.5x.
+    pub const fn ratio(numerator: usize, denominator: usize) -> Scale {
+        Scale(((numerator * ONE) + (denominator / 2)) / denominator)
+    }
+
+    /// Whether this is 1x, 2x, 3x... with nothing after the point
+    pub fn is_whole(&self) -> bool {
+        return self.0 % ONE == 0;
+    }
+
+    /// The factor rounded down to a whole number
+    pub fn whole_part(&self) -> usize {
+        return self.0 / ONE;
+    }
+
+    /// How big `px` pixels end up, rounded to the nearest pixel
+    pub fn apply(&self, px: usize) -> usize {
+        return ((px * self.0) + (ONE / 2)) / ONE;
+    }
+}
+
+impl From<usize> for Scale {
+    fn from(factor: usize) -> Scale {
+        return Scale::whole(factor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn whole() {
+        let scale = Scale::whole(3);
+        assert!(scale.is_whole());
+        assert_eq!(scale.whole_part(), 3);
+        assert_eq!(scale.apply(9), 27);
+        assert_eq!(Scale::from(2), Scale::whole(2));
+    }
+
+    #[test]
+    fn ratio() {
+        let scale = Scale::ratio(3, 2);
+        assert!(!scale.is_whole());
+        assert_eq!(scale.whole_part(), 1);
+        assert_eq!(scale.apply(10), 15);
+        // 13.5 rounds up
+        assert_eq!(scale.apply(9), 14);
+        assert_eq!(Scale::ratio(4, 2), Scale::whole(2));
+        assert!(Scale::ratio(4, 2).is_whole());
+    }
+
+    #[test]
+    fn ordered_by_size() {
+        assert!(Scale::ratio(3, 2) > Scale::whole(1));
+        assert!(Scale::ratio(3, 2) < Scale::whole(2));
+    }
+}
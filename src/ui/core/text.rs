@@ -0,0 +1,268 @@
+use no_std_compat::prelude::v1::vec;
+use no_std_compat::string::String;
+use no_std_compat::vec::Vec;
+
+/// What `Graphics::draw_text()` puts at the end of a line that got cut short
+pub const ELLIPSIS: &str = "...";
+
+/// Where text sits left to right inside its `Rect`
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(dead_code)]
+pub enum HAlign {
+    Left,
+    Center,
+    Right,
+}
+
+/// Where text sits top to bottom inside its `Rect`
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(dead_code)]
+pub enum VAlign {
+    Top,
+    Middle,
+    Bottom,
+}
+
+/// What to do with a line that's wider than its `Rect`
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(dead_code)]
+pub enum TextOverflow {
+    /// Draw it anyway and cut it off at the edge
+    Clip,
+    /// Cut it short and end it with `ELLIPSIS`
+    Ellipsis,
+    /// Break it onto more lines, between words where possible
+    Wrap,
+}
+
+/// How `Graphics::draw_text()` lays text out in its `Rect`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TextOptions {
+    pub halign: HAlign,
+    pub valign: VAlign,
+    pub overflow: TextOverflow,
+}
+
+impl Default for TextOptions {
+    /// Top-left and clipped, like `write_text()` but kept inside the `Rect`
+    fn default() -> TextOptions {
+        TextOptions {
+            halign: HAlign::Left,
+            valign: VAlign::Top,
+            overflow: TextOverflow::Clip,
+        }
+    }
+}
+
+/// Break `text` into the lines `Graphics::draw_text()` will draw. Every `\n`
+/// starts a new line; `overflow` decides what happens to lines wider than
+/// `max_width` px. `advance` is how wide a character is in px.
+pub fn lines(
+    text: &str,
+    max_width: usize,
+    overflow: TextOverflow,
+    advance: impl Fn(char) -> usize,
+) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.split('\n') {
+        match overflow {
+            TextOverflow::Clip => lines.push(String::from(line)),
+            TextOverflow::Ellipsis => lines.push(ellipsize(line, max_width, &advance)),
+            TextOverflow::Wrap => lines.extend(wrap(line, max_width, &advance)),
+        }
+    }
+    return lines;
+}
+
+/// `line` if it fits in `max_width` px, otherwise as much of it as fits
+/// followed by `ELLIPSIS`. If even `ELLIPSIS` is too wide it's cut short
+/// itself, so nothing returned is ever wider than `max_width`.
+fn ellipsize(line: &str, max_width: usize, advance: &impl Fn(char) -> usize) -> String {
+    let width = |s: &str| -> usize { s.chars().map(advance).sum() };
+    if width(line) <= max_width {
+        return String::from(line);
+    }
+    if width(ELLIPSIS) > max_width {
+        let mut used = 0;
+        return ELLIPSIS
+            .chars()
+            .take_while(|c| {
+                used += advance(*c);
+                used <= max_width
+            })
+            .collect();
+    }
+    let room = max_width - width(ELLIPSIS);
+    let mut used = 0;
+    let mut end = 0;
+    for (idx, c) in line.char_indices() {
+        used += advance(c);
+        if used > room {
+            break;
+        }
+        end = idx + c.len_utf8();
+    }
+    // no point leaving a space hanging before the dots
+    return String::from(line[..end].trim_end()) + ELLIPSIS;
+}
+
+/// Break `line` between words so no piece is wider than `max_width` px.
+/// Words too long for a line of their own get broken wherever they have to
+/// be. Spaces a line ends on don't count towards its width.
+fn wrap(line: &str, max_width: usize, advance: &impl Fn(char) -> usize) -> Vec<String> {
+    let width = |s: &str| -> usize { s.chars().map(advance).sum() };
+    let mut wrapped: Vec<String> = vec![String::new()];
+    for word in line.split_inclusive(' ') {
+        let last = wrapped.last_mut().expect("Shouldn't be empty");
+        if width(last) + width(word.trim_end()) <= max_width {
+            last.push_str(word);
+        } else if width(word.trim_end()) <= max_width {
+            wrapped.push(String::from(word));
+        } else {
+            let trimmed = word.trim_end();
+            for c in trimmed.chars() {
+                let last = wrapped.last_mut().expect("Shouldn't be empty");
+                if width(last) + advance(c) > max_width && !last.is_empty() {
+                    wrapped.push(String::new());
+                }
+                wrapped.last_mut().expect("Shouldn't be empty").push(c);
+            }
+            // the space after it stays on its line instead of starting the next
+            let last = wrapped.last_mut().expect("Shouldn't be empty");
+            last.push_str(&word[trimmed.len()..]);
+        }
+    }
+    return wrapped
+        .iter()
+        .map(|line| String::from(line.trim_end()))
+        .collect();
+}
+
+/// How far into `available` px something `used` px wide starts so it sits
+/// where `align` says. Things that don't fit start at 0.
+pub fn x_offset(available: usize, used: usize, align: HAlign) -> usize {
+    let spare = available.saturating_sub(used);
+    return match align {
+        HAlign::Left => 0,
+        HAlign::Center => spare / 2,
+        HAlign::Right => spare,
+    };
+}
+
+/// Same as `x_offset()` going down instead of across
+pub fn y_offset(available: usize, used: usize, align: VAlign) -> usize {
+    let spare = available.saturating_sub(used);
+    return match align {
+        VAlign::Top => 0,
+        VAlign::Middle => spare / 2,
+        VAlign::Bottom => spare,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every character 9px wide, like the built-in font at 1x
+    fn mono(_: char) -> usize {
+        return 9;
+    }
+
+    fn lines_of(text: &str, max_width: usize, overflow: TextOverflow) -> Vec<String> {
+        return lines(text, max_width, overflow, mono);
+    }
+
+    #[test]
+    fn newlines_always_break() {
+        for overflow in [
+            TextOverflow::Clip,
+            TextOverflow::Ellipsis,
+            TextOverflow::Wrap,
+        ] {
+            assert_eq!(lines_of("ab\ncd", 100, overflow), ["ab", "cd"]);
+            assert_eq!(lines_of("ab\n", 100, overflow), ["ab", ""]);
+        }
+    }
+
+    #[test]
+    fn clip_leaves_lines_alone() {
+        assert_eq!(
+            lines_of("hello world", 20, TextOverflow::Clip),
+            ["hello world"]
+        );
+    }
+
+    #[test]
+    fn ellipsis() {
+        // fits exactly
+        assert_eq!(lines_of("hello", 45, TextOverflow::Ellipsis), ["hello"]);
+        // 6 characters of room, 3 of them go to the dots
+        assert_eq!(
+            lines_of("hello world", 54, TextOverflow::Ellipsis),
+            ["hel..."]
+        );
+        // no space left dangling before the dots
+        assert_eq!(lines_of("ab cdef", 54, TextOverflow::Ellipsis), ["ab..."]);
+    }
+
+    #[test]
+    fn ellipsis_wider_than_the_line() {
+        // 27px of dots don't fit in 20px, so only as many as do
+        assert_eq!(lines_of("hello world", 20, TextOverflow::Ellipsis), [".."]);
+        assert_eq!(lines_of("hello world", 27, TextOverflow::Ellipsis), ["..."]);
+        assert_eq!(lines_of("hello world", 5, TextOverflow::Ellipsis), [""]);
+    }
+
+    #[test]
+    fn ellipsis_on_char_boundaries() {
+        assert_eq!(
+            lines_of("héllo wörld", 54, TextOverflow::Ellipsis),
+            ["hél..."]
+        );
+    }
+
+    #[test]
+    fn wrap_between_words() {
+        assert_eq!(
+            lines_of("the quick brown fox", 90, TextOverflow::Wrap),
+            ["the quick", "brown fox"]
+        );
+        // trailing spaces don't count, so "the quick " fits in 9 characters
+        assert_eq!(
+            lines_of("the quick brown", 81, TextOverflow::Wrap),
+            ["the quick", "brown"]
+        );
+    }
+
+    #[test]
+    fn wrap_breaks_long_words() {
+        assert_eq!(
+            lines_of("abcdefgh ij", 36, TextOverflow::Wrap),
+            ["abcd", "efgh", "ij"]
+        );
+        // even a single character too wide for the line gets a line to itself
+        assert_eq!(lines_of("ab", 5, TextOverflow::Wrap), ["a", "b"]);
+    }
+
+    #[test]
+    fn proportional_advance() {
+        let advance = |c: char| if c == 'i' { 3 } else { 9 };
+        assert_eq!(
+            lines("iiiiii mm", 18, TextOverflow::Wrap, advance),
+            ["iiiiii", "mm"]
+        );
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(x_offset(100, 40, HAlign::Left), 0);
+        assert_eq!(x_offset(100, 40, HAlign::Center), 30);
+        assert_eq!(x_offset(100, 40, HAlign::Right), 60);
+        assert_eq!(y_offset(50, 20, VAlign::Top), 0);
+        assert_eq!(y_offset(50, 20, VAlign::Middle), 15);
+        assert_eq!(y_offset(50, 20, VAlign::Bottom), 30);
+        // too big to fit starts at the edge instead of underflowing
+        assert_eq!(x_offset(10, 40, HAlign::Right), 0);
+        assert_eq!(y_offset(10, 40, VAlign::Middle), 0);
+    }
+}
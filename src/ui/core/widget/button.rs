@@ -3,7 +3,9 @@ use uefi::proto::console::text::Key;
 use no_std_compat::string::String;
 use no_std_compat::vec::Vec;
 
-use crate::ui::core::{font, graphics, UIResult};
+use crate::ui::core::rect::Rect;
+use crate::ui::core::text::{HAlign, TextOptions, TextOverflow, VAlign};
+use crate::ui::core::{graphics, UIResult};
 use crate::widget::Widget;
 use graphics::{ColorType, FontSize, Graphics};
 
@@ -63,17 +65,18 @@ impl Widget for Button {
             Some(border),
         );
 
-        let char_width = graphics.theme.font_sizes.get(self.font_size) * font::FONT_WIDTH;
-        let char_height = graphics.theme.font_sizes.get(self.font_size) * font::FONT_HEIGHT;
-
-        let x_offset =
-            self.start_px.0 + ((self.dimensions_px.0 - (self.label.len() * char_width)) / 2);
-        let y_offset = self.start_px.1 + ((self.dimensions_px.1 - char_height) / 2);
-        let mut next_char_px = (x_offset, y_offset);
-        for c in self.label.chars() {
-            graphics.write_char(c, next_char_px, FontSize::P, ColorType::Foreground);
-            next_char_px.0 += char_width;
-        }
+        // labels too long for the button get cut short rather than spilling out
+        graphics.draw_text(
+            &self.label,
+            self.bounds(),
+            TextOptions {
+                halign: HAlign::Center,
+                valign: VAlign::Middle,
+                overflow: TextOverflow::Ellipsis,
+            },
+            self.font_size,
+            ColorType::Foreground,
+        );
     }
 
     fn handle_key(&mut self, k: Key, _graphics: &mut Graphics) -> UIResult {
@@ -89,4 +92,13 @@ impl Widget for Button {
     fn dimensions(&mut self) -> (usize, usize) {
         return self.dimensions_px;
     }
+
+    fn bounds(&self) -> Rect {
+        return Rect::new(self.start_px, self.dimensions_px);
+    }
+
+    fn resize(&mut self, _graphics: &Graphics, rect: Rect) {
+        self.start_px = rect.top_left();
+        self.dimensions_px = rect.dimensions();
+    }
 }
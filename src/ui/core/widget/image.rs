@@ -0,0 +1,290 @@
+use uefi::proto::console::text::{Key, ScanCode};
+
+use no_std_compat::string::String;
+use no_std_compat::vec::Vec;
+
+use crate::bitmap::{Bitmap, Filter};
+use crate::ui::core::rect::Rect;
+use crate::ui::core::scale::Scale;
+use crate::ui::core::{codec, graphics, UIResult};
+use crate::widget::Widget;
+use graphics::{ColorType, Graphics};
+
+/// How an `Image` fits its picture into its bounds
+#[derive(Clone, Copy, PartialEq)]
+#[allow(dead_code)]
+pub enum ImageMode {
+    /// As big as possible while showing all of it. Keeps the aspect ratio.
+    Fit,
+    /// As small as possible while covering all of the bounds. Keeps the aspect
+    /// ratio; whatever hangs over gets cut off.
+    Fill,
+    /// Actual size, in the middle. Cut off if it doesn't fit.
+    Center,
+}
+
+/// Shows a picture. Escape closes it.
+pub struct Image {
+    id: String,
+    subscriptions: Vec<String>,
+    start: (usize, usize),
+    dimensions_px: (usize, usize),
+    source: Bitmap,
+    mode: ImageMode,
+
+    // `source` resized for the current bounds, made on the next `draw()`.
+    // stays `None` if `source` is already the right size
+    scaled: Option<Bitmap>,
+}
+
+impl Image {
+    /// start: (x, y) coordinates of the top-left corner (in px)
+    /// dimensions_px: (x, y) dimensions in px
+    pub fn new(
+        id: String,
+        source: Bitmap,
+        mode: ImageMode,
+        start: (usize, usize),
+        dimensions_px: (usize, usize),
+    ) -> Image {
+        Image {
+            id: id,
+            subscriptions: Vec::new(),
+            start: start,
+            dimensions_px: dimensions_px,
+            source: source,
+            mode: mode,
+            scaled: None,
+        }
+    }
+
+    /// Decode a BMP or QOI file, e.g. one from `include_bytes!()` or
+    /// `Application::read_file()`.
+    pub fn from_bytes(
+        id: String,
+        data: &[u8],
+        mode: ImageMode,
+        start: (usize, usize),
+        dimensions_px: (usize, usize),
+    ) -> Result<Image, &'static str> {
+        let source = codec::decode(data)?;
+        return Ok(Image::new(id, source, mode, start, dimensions_px));
+    }
+
+    /// Size the picture gets drawn at for the current bounds and mode
+    fn scaled_dimensions(&self) -> (usize, usize) {
+        let (cols, rows) = self.source.dimensions();
+        let (width, height) = self.dimensions_px;
+        // compare width/cols against height/rows without dividing
+        let wide = width * rows > height * cols;
+        let by_width = (width, (rows * width + cols / 2) / cols);
+        let by_height = ((cols * height + rows / 2) / rows, height);
+        return match self.mode {
+            ImageMode::Center => (cols, rows),
+            ImageMode::Fit if wide => by_height,
+            ImageMode::Fit => by_width,
+            ImageMode::Fill if wide => by_width,
+            ImageMode::Fill => by_height,
+        };
+    }
+
+    /// `source` at `dims`. Whole multiples keep their hard edges so pixel art
+    /// stays pixel art, everything else gets smoothed.
+    fn resample(source: &Bitmap, dims: (usize, usize)) -> Bitmap {
+        let (cols, rows) = source.dimensions();
+        let factor = dims.0 / cols;
+        if factor > 1 && dims == (cols * factor, rows * factor) {
+            return source.scale(Scale::whole(factor), Filter::Nearest);
+        }
+        return source.resize(dims, Filter::Bilinear);
+    }
+}
+
+impl Widget for Image {
+    fn id(&self) -> &String {
+        return &self.id;
+    }
+
+    fn get_subscriptions(&self) -> &Vec<String> {
+        return &self.subscriptions;
+    }
+
+    fn draw(&mut self, graphics: &mut Graphics, _focused: bool) {
+        let bounds = self.bounds();
+        graphics.fill_rect(bounds, ColorType::Background);
+
+        let dims = self.scaled_dimensions();
+        if dims.0 == 0 || dims.1 == 0 {
+            return;
+        }
+        let bitmap = if dims == self.source.dimensions() {
+            &mut self.source
+        } else {
+            let source = &self.source;
+            self.scaled
+                .get_or_insert_with(|| Image::resample(source, dims))
+        };
+
+        // centered, and clipped if it hangs over. can't start left of or above
+        // the screen though, so there it hangs over the other way
+        let x = (bounds.x + bounds.width / 2).saturating_sub(dims.0 / 2);
+        let y = (bounds.y + bounds.height / 2).saturating_sub(dims.1 / 2);
+        graphics.push_clip(bounds);
+        graphics.draw_bitmap(bitmap, (x, y));
+        graphics.pop_clip();
+    }
+
+    fn handle_key(&mut self, k: Key, _graphics: &mut Graphics) -> UIResult {
+        if k == Key::Special(ScanCode::ESCAPE) {
+            return UIResult::CLOSE;
+        }
+        return UIResult::OK;
+    }
+
+    fn dimensions(&mut self) -> (usize, usize) {
+        return self.dimensions_px;
+    }
+
+    fn bounds(&self) -> Rect {
+        return Rect::new(self.start, self.dimensions_px);
+    }
+
+    fn resize(&mut self, _graphics: &Graphics, rect: Rect) {
+        self.start = rect.top_left();
+        self.dimensions_px = rect.dimensions();
+        self.scaled = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitmap::Pixel;
+    use crate::graphics::{ColorScheme, FontSizes, Theme};
+    use crate::ui::core::font;
+    use crate::ui::core::render_target::MemoryTarget;
+    use no_std_compat::prelude::v1::Box;
+
+    const RED: Pixel = Pixel::new([255, 0, 0], 255);
+
+    fn graphics(resolution: (usize, usize)) -> Graphics<'static> {
+        let theme = Theme {
+            font_sizes: FontSizes::new(1, 1, 1),
+            color_scheme: ColorScheme::new(
+                [255, 255, 255],
+                [0, 0, 0],
+                [0, 255, 0],
+                [90, 90, 90],
+                [0, 0, 255],
+            ),
+            font: String::from(font::BUILTIN),
+        };
+        return Graphics::new(Box::new(MemoryTarget::new(resolution)), theme);
+    }
+
+    /// A solid `cols` x `rows` picture in a `dimensions_px` box
+    fn image(cols: usize, rows: usize, mode: ImageMode, dimensions_px: (usize, usize)) -> Image {
+        let source = Bitmap::new(rows, cols, vec![RED; cols * rows].into_boxed_slice(), None);
+        return Image::new(String::from("image"), source, mode, (0, 0), dimensions_px);
+    }
+
+    /// The color at `pos` once it's on screen, out of a screenshot
+    fn shown(graphics: &mut Graphics, pos: (usize, usize)) -> [u8; 3] {
+        graphics.present();
+        let bmp = graphics.capture();
+        let (width, height) = graphics.resolution();
+        // 24-bit rows padded to 4 bytes, bottom row first, after a 54 byte header
+        let row_size = ((width * 3) + 3) & !3;
+        let i = 54 + ((height - 1 - pos.1) * row_size) + (pos.0 * 3);
+        return [bmp[i + 2], bmp[i + 1], bmp[i]];
+    }
+
+    #[test]
+    fn fit_shows_all_of_it() {
+        assert_eq!(
+            image(4, 2, ImageMode::Fit, (10, 10)).scaled_dimensions(),
+            (10, 5)
+        );
+        assert_eq!(
+            image(2, 4, ImageMode::Fit, (10, 10)).scaled_dimensions(),
+            (5, 10)
+        );
+    }
+
+    #[test]
+    fn fill_covers_everything() {
+        assert_eq!(
+            image(4, 2, ImageMode::Fill, (10, 10)).scaled_dimensions(),
+            (20, 10)
+        );
+        assert_eq!(
+            image(2, 4, ImageMode::Fill, (10, 10)).scaled_dimensions(),
+            (10, 20)
+        );
+    }
+
+    #[test]
+    fn center_keeps_it_as_is() {
+        let mut image = image(4, 2, ImageMode::Center, (10, 10));
+        assert_eq!(image.scaled_dimensions(), (4, 2));
+        let mut graphics = graphics((10, 10));
+        image.draw(&mut graphics, true);
+        assert!(image.scaled.is_none());
+        // in the middle of the 10x10 box
+        assert_eq!(shown(&mut graphics, (3, 4)), [255, 0, 0]);
+        assert_eq!(shown(&mut graphics, (6, 5)), [255, 0, 0]);
+        assert_eq!(shown(&mut graphics, (2, 4)), [0, 0, 0]);
+    }
+
+    #[test]
+    fn resized_once_until_bounds_change() {
+        let mut image = image(4, 2, ImageMode::Fit, (8, 8));
+        let mut graphics = graphics((10, 10));
+        image.draw(&mut graphics, true);
+        assert_eq!(image.scaled.as_ref().map(|b| b.dimensions()), Some((8, 4)));
+        image.resize(&graphics, Rect::new((0, 0), (6, 6)));
+        assert!(image.scaled.is_none());
+        image.draw(&mut graphics, true);
+        assert_eq!(image.scaled.as_ref().map(|b| b.dimensions()), Some((6, 3)));
+    }
+
+    #[test]
+    fn whole_multiples_stay_sharp() {
+        let blue = Pixel::new([0, 0, 255], 255);
+        let source = Bitmap::new(1, 2, vec![RED, blue].into_boxed_slice(), None);
+        let mut image = Image::new(
+            String::from("image"),
+            source,
+            ImageMode::Fit,
+            (0, 0),
+            (4, 2),
+        );
+        let mut screen = graphics((4, 2));
+        image.draw(&mut screen, true);
+        assert_eq!(shown(&mut screen, (1, 1)), [255, 0, 0]);
+        assert_eq!(shown(&mut screen, (2, 1)), [0, 0, 255]);
+        // 1.5x has to blend somewhere
+        let source = Bitmap::new(1, 2, vec![RED, blue].into_boxed_slice(), None);
+        let mut image = Image::new(
+            String::from("image"),
+            source,
+            ImageMode::Fit,
+            (0, 0),
+            (3, 2),
+        );
+        let mut smaller = graphics((3, 2));
+        image.draw(&mut smaller, true);
+        let middle = shown(&mut smaller, (1, 0));
+        assert!(middle != [255, 0, 0] && middle != [0, 0, 255]);
+    }
+
+    #[test]
+    fn escape_closes() {
+        let mut image = image(1, 1, ImageMode::Fit, (8, 8));
+        let mut graphics = graphics((10, 10));
+        let key = Key::Special(ScanCode::ESCAPE);
+        assert!(image.handle_key(key, &mut graphics) == UIResult::CLOSE);
+        let key = Key::Special(ScanCode::UP);
+        assert!(image.handle_key(key, &mut graphics) == UIResult::OK);
+    }
+}
@@ -4,6 +4,7 @@ use no_std_compat::string::String;
 use no_std_compat::vec::Vec;
 
 use crate::graphics::Graphics;
+use crate::ui::core::rect::Rect;
 use crate::ui::core::UIResult;
 
 // exposed
@@ -12,11 +13,15 @@ mod multi_widget;
 pub use multi_widget::MultiWidget;
 
 mod text_area;
-pub use text_area::{TextArea, XOverflowBehavior};
+pub use text_area::{TextArea, TextAreaOptions, XOverflowBehavior};
 
 mod button;
 pub use button::Button;
 
+mod image;
+#[allow(unused_imports)]
+pub use image::{Image, ImageMode};
+
 /// America runs on `Widget`s
 /// `Widget`s can be drawn, receive keypresses, post/listen for events.
 /// A `Widget` can own and coordinate multiple `Widget`s - see `MultiWidget`.
@@ -41,10 +46,27 @@ pub trait Widget {
     /// Not much else to say!
     fn draw(&mut self, graphics: &mut Graphics, focused: bool);
 
+    /// Repaint whatever part of this `Widget` overlaps `rect`. By default
+    /// that's a full `draw()`; `Widget`s that can repaint just a piece of
+    /// themselves (one character cell, one child) should override this.
+    fn invalidate(&mut self, graphics: &mut Graphics, rect: Rect, focused: bool) {
+        if self.bounds().intersects(&rect) {
+            self.draw(graphics, focused);
+        }
+    }
+
     /// Handle any keypress. `Widget`s can use this to move a cursor, write text
     /// to the screen, close themselves, whatever.
     fn handle_key(&mut self, k: Key, graphics: &mut Graphics) -> UIResult;
 
     /// Return this `Widget`'s dimensions in pixels. Not really using this!
     fn dimensions(&mut self) -> (usize, usize);
+
+    /// Where on screen this `Widget` draws itself, in px.
+    fn bounds(&self) -> Rect;
+
+    /// Move this `Widget` to `rect` and lay everything in it out again, e.g.
+    /// after the resolution or font size changed. Doesn't draw anything; a
+    /// `draw()` is expected afterwards.
+    fn resize(&mut self, graphics: &Graphics, rect: Rect);
 }
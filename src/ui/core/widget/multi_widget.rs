@@ -4,15 +4,21 @@ use no_std_compat::prelude::v1::Box;
 use no_std_compat::string::String;
 use no_std_compat::vec::Vec;
 
-use crate::graphics::Graphics;
+use crate::graphics::{ColorType, Graphics};
+use crate::ui::core::rect::Rect;
 use crate::ui::core::UIResult;
 use crate::widget::Widget;
 
+/// How much of the background color gets blended over components that aren't
+/// focused (out of 255).
+const UNFOCUSED_TINT: u8 = 96;
+
 /// A `Widget` that owns and coordinates multiple `Widget`s.
 pub struct MultiWidget {
     id: String,
     components: Vec<Box<dyn Widget>>,
     focused: usize,
+    start: (usize, usize),
     dimensions: (usize, usize),
 
     // computed
@@ -23,16 +29,19 @@ impl MultiWidget {
     /// Create a new `MultiWidget`. Keypresses are delivered to the "focused"
     /// `Widget`, except maybe ^W will be intercepted here to rotate focus.
     /// In the future resizing/tiling may be supported which will make
-    /// `dimensions` matter more.
+    /// `dimensions` matter more. Components are clipped to `start` and
+    /// `dimensions` when drawn.
     ///
     /// id: not important for MultiWidget as no data gets posted
     /// components: the list of `Widget`s controlled by this `MultiWidget`
     /// focused: the index in `components` of the "focused" `Widget`
+    /// start: (x, y) coordinates of the top-left corner (in px)
     /// dimensions: the dimensions that `Widget`s should be contained within
     pub fn new(
         id: String,
         components: Vec<Box<dyn Widget>>,
         focused: usize,
+        start: (usize, usize),
         dimensions: (usize, usize),
     ) -> MultiWidget {
         // doesn't filter out unique subscriptions but that's fine
@@ -45,6 +54,7 @@ impl MultiWidget {
             subscriptions: subscriptions,
             components: components,
             focused: focused,
+            start: start,
             dimensions: dimensions,
         }
     }
@@ -63,17 +73,24 @@ impl MultiWidget {
             .get_value();
     }
 
+    /// Move the component at `idx` to `rect`. `MultiWidget` has no idea how
+    /// its components should be arranged, so whoever built it lays them out.
+    pub fn resize_component(&mut self, graphics: &Graphics, idx: usize, rect: Rect) {
+        self.components[idx].resize(graphics, rect);
+    }
+
     /// Rotate focus to the previous component in the list.
     /// The focused component is drawn on top and receives all the keystrokes
     /// given to the `MultiWidget`.
     pub fn focus_prev(&mut self, graphics: &mut Graphics) {
         if self.components.len() >= 2 {
+            let prev = self.focused;
             if self.focused == 0 {
                 self.focused = self.components.len() - 1;
             } else {
                 self.focused -= 1;
             }
-            self.draw(graphics, true);
+            self.draw_focus_change(graphics, prev);
         }
     }
 
@@ -82,10 +99,28 @@ impl MultiWidget {
     /// given to the `MultiWidget`.
     pub fn focus_next(&mut self, graphics: &mut Graphics) {
         if self.components.len() >= 2 {
+            let prev = self.focused;
             self.focused = (self.focused + 1) % self.components.len();
-            self.draw(graphics, true);
+            self.draw_focus_change(graphics, prev);
         }
     }
+
+    /// Only the `Widget` losing focus and the one gaining it look any
+    /// different, so don't bother repainting the rest.
+    fn draw_focus_change(&mut self, graphics: &mut Graphics, prev: usize) {
+        graphics.push_clip(self.bounds());
+        self.draw_unfocused(graphics, prev);
+        self.components[self.focused].draw(graphics, true);
+        graphics.pop_clip();
+    }
+
+    /// Draw a component that isn't focused and dim it so the focused one
+    /// stands out.
+    fn draw_unfocused(&mut self, graphics: &mut Graphics, idx: usize) {
+        self.components[idx].draw(graphics, false);
+        let bounds = self.components[idx].bounds();
+        graphics.tint(bounds, ColorType::Background, UNFOCUSED_TINT);
+    }
 }
 
 impl Widget for MultiWidget {
@@ -111,14 +146,36 @@ impl Widget for MultiWidget {
     }
 
     fn draw(&mut self, graphics: &mut Graphics, focused: bool) {
-        // figure out a way to tint out-of-focus components
-        // also a way to tint all components for when there is a higher layer
+        graphics.push_clip(self.bounds());
         for i in 0..self.components.len() {
             if i != self.focused {
-                self.components[i].draw(graphics, false);
+                self.draw_unfocused(graphics, i);
             }
         }
         self.components[self.focused].draw(graphics, focused);
+        graphics.pop_clip();
+    }
+
+    fn invalidate(&mut self, graphics: &mut Graphics, rect: Rect, focused: bool) {
+        graphics.push_clip(self.bounds());
+        // children are free to redraw more than `rect` (a `Button` always
+        // redraws all of itself), but only `rect` gets tinted below, so don't
+        // let anything outside it change
+        graphics.push_clip(rect);
+        for i in 0..self.components.len() {
+            let bounds = self.components[i].bounds();
+            if i != self.focused && bounds.intersects(&rect) {
+                self.components[i].invalidate(graphics, rect, false);
+                if let Some(repainted) = bounds.intersection(&rect) {
+                    graphics.tint(repainted, ColorType::Background, UNFOCUSED_TINT);
+                }
+            }
+        }
+        if self.components[self.focused].bounds().intersects(&rect) {
+            self.components[self.focused].invalidate(graphics, rect, focused);
+        }
+        graphics.pop_clip();
+        graphics.pop_clip();
     }
 
     fn handle_key(&mut self, k: Key, graphics: &mut Graphics) -> UIResult {
@@ -149,4 +206,14 @@ impl Widget for MultiWidget {
     fn dimensions(&mut self) -> (usize, usize) {
         return self.dimensions;
     }
+
+    fn bounds(&self) -> Rect {
+        return Rect::new(self.start, self.dimensions);
+    }
+
+    /// Only moves the `MultiWidget` itself; see `resize_component()`.
+    fn resize(&mut self, _graphics: &Graphics, rect: Rect) {
+        self.start = rect.top_left();
+        self.dimensions = rect.dimensions();
+    }
 }
@@ -6,7 +6,8 @@ use no_std_compat::string::String;
 use no_std_compat::string::ToString;
 use no_std_compat::vec::Vec;
 
-use crate::ui::core::{font, graphics, UIResult};
+use crate::ui::core::rect::Rect;
+use crate::ui::core::{graphics, UIResult};
 use crate::widget::Widget;
 use graphics::{ColorType, FontSize, Graphics};
 
@@ -26,9 +27,31 @@ pub enum XOverflowBehavior {
     Scroll,
 }
 
+/// How a `TextArea` sets its text
+pub struct TextAreaOptions {
+    /// Controls the size of the text, and so how many chars fit on a line
+    pub font_size: FontSize,
+    /// Whether to scroll or wrap text that's too wide to be displayed
+    pub x_overflow: XOverflowBehavior,
+    /// Give every character a whole cell, like a terminal
+    pub monospace: bool,
+}
+
+impl Default for TextAreaOptions {
+    /// Paragraph sized, wrapped and proportional
+    fn default() -> TextAreaOptions {
+        TextAreaOptions {
+            font_size: FontSize::P,
+            x_overflow: XOverflowBehavior::Wrap,
+            monospace: false,
+        }
+    }
+}
+
 /// Primitive `Widget` to display and optionally edit text. Supports wrapping
-/// text when lines exceed the available space, or scrolling. A building block
-/// for more complex `Widget`s
+/// text when lines exceed the available space, or scrolling. Text is set
+/// proportionally unless it's in monospace mode, where every character gets a
+/// whole cell. A building block for more complex `Widget`s
 pub struct TextArea {
     id: String,
     subscriptions: Vec<String>,
@@ -38,12 +61,17 @@ pub struct TextArea {
     dimensions_px: (usize, usize),
     font_size: FontSize,
     x_overflow: XOverflowBehavior,
+    monospace: bool,
 
     // computed
+    // (byte index into the row, row). always on a char boundary
     cursor: (usize, usize),
+    // (first char shown on each row, first row shown). the x is in chars rather
+    // than bytes since rows don't line up byte for byte
     viewport_start: (usize, usize),
 
     // need `Graphics` to init. does Rust anything like C++'s std::call_once()?
+    // cleared by `resize()` so the grid gets recomputed
     char_dims_set: bool,
     char_width: usize,
     char_height: usize,
@@ -57,8 +85,7 @@ impl TextArea {
     /// content: initial content to display in the `TextArea`.
     /// start: (x, y) coordinates of the top-left corner (in px)
     /// dimensions_px: (x, y) dimensions in px
-    /// font_size: controls the size of the text displayed (thus how many chars fit on a line)
-    /// x_overflow: whether to scroll or wrap text that is too wide to be displayed
+    /// options: font size, overflow and spacing, see `TextAreaOptions`
     pub fn new(
         id: String,
         subscriptions: Vec<String>,
@@ -66,8 +93,7 @@ impl TextArea {
         edit: bool,
         start: (usize, usize),
         dimensions_px: (usize, usize),
-        font_size: FontSize,
-        x_overflow: XOverflowBehavior,
+        options: TextAreaOptions,
     ) -> TextArea {
         let content: Vec<String> = content
             .split(&['\n', '\r'][..])
@@ -83,8 +109,9 @@ impl TextArea {
             edit: edit,
             start: start,
             dimensions_px: dimensions_px,
-            font_size: font_size,
-            x_overflow: x_overflow,
+            font_size: options.font_size,
+            x_overflow: options.x_overflow,
+            monospace: options.monospace,
             cursor: cursor,
             viewport_start: viewport_start,
             char_dims_set: false,
@@ -94,79 +121,183 @@ impl TextArea {
         }
     }
 
-    /// Compute where on the screen the cursor should be drawn based on:
+    /// How wide `c` is in px: a whole cell in monospace mode, however far it
+    /// moves the pen otherwise.
+    fn char_advance(&self, graphics: &Graphics, c: char) -> usize {
+        if self.monospace {
+            return self.char_width;
+        }
+        return graphics.char_advance(c, self.font_size);
+    }
+
+    fn text_width(&self, graphics: &Graphics, s: &str) -> usize {
+        return s.chars().map(|c| self.char_advance(graphics, c)).sum();
+    }
+
+    /// How much of a line text can take up, in px
+    fn line_width(&self) -> usize {
+        if self.monospace {
+            return self.dimensions_chars.0 * self.char_width;
+        }
+        return self.dimensions_px.0;
+    }
+
+    /// Compute where the cursor is as (px from the left, row) relative to the
+    /// viewport based on:
     /// - cursor position
     /// - overflow behavior
     /// - viewport position
-    /// Only draw the cursor in edit mode.
-    fn maybe_draw_cursor(&self, graphics: &mut Graphics) {
-        if self.edit {
-            let pos_in_viewport = match self.x_overflow {
-                XOverflowBehavior::Scroll => {
-                    let col = self.cursor.0 - self.viewport_start.0;
-                    let row = self.cursor.1 - self.viewport_start.1;
-                    (col, row)
-                }
-                XOverflowBehavior::Wrap => {
-                    let rows = self.dimensions_chars.1;
-                    let start_row = self.viewport_start.1;
-                    let max_end_row = cmp::min(self.content.len(), self.viewport_start.1 + rows);
-                    let mut row_idx = start_row;
-                    let mut pos = (0, 0);
-                    for row in &self.content[start_row..max_end_row] {
-                        let wrapped = self.wrap_line(row);
-                        if row_idx != self.cursor.1 {
-                            pos.1 += wrapped.len();
-                        } else {
-                            // figure out which line our cursor should be in. clamp to row length
-                            // even though we're fine drawing the cursor one space further.
-                            let cursor_region = wrapped
-                                .iter()
-                                .position(|(l, r)| l <= &self.cursor.0 && &self.cursor.0 <= r)
-                                .expect("should have found it");
-                            pos.1 += cursor_region;
-                            pos.0 = self.cursor.0 - wrapped[cursor_region].0;
-                            break;
-                        }
-                        row_idx += 1;
+    fn cursor_pos_in_viewport(&self, graphics: &Graphics) -> (usize, usize) {
+        let line = &self.content[self.cursor.1];
+        return match self.x_overflow {
+            XOverflowBehavior::Scroll => {
+                let x = line[..self.cursor.0]
+                    .chars()
+                    .skip(self.viewport_start.0)
+                    .map(|c| self.char_advance(graphics, c))
+                    .sum();
+                let row = self.cursor.1 - self.viewport_start.1;
+                (x, row)
+            }
+            XOverflowBehavior::Wrap => {
+                let rows = self.dimensions_chars.1;
+                let start_row = self.viewport_start.1;
+                let max_end_row = cmp::min(self.content.len(), self.viewport_start.1 + rows);
+                let mut row_idx = start_row;
+                let mut pos = (0, 0);
+                for row in &self.content[start_row..max_end_row] {
+                    let wrapped = self.wrap_line(graphics, row);
+                    if row_idx != self.cursor.1 {
+                        pos.1 += wrapped.len();
+                    } else {
+                        // figure out which line our cursor should be in. clamp to row length
+                        // even though we're fine drawing the cursor one space further.
+                        let cursor_region = wrapped
+                            .iter()
+                            .position(|(l, r)| l <= &self.cursor.0 && &self.cursor.0 <= r)
+                            .expect("should have found it");
+                        pos.1 += cursor_region;
+                        pos.0 = self
+                            .text_width(graphics, &line[wrapped[cursor_region].0..self.cursor.0]);
+                        break;
                     }
-                    pos
+                    row_idx += 1;
                 }
-            };
+                pos
+            }
+        };
+    }
 
-            let cell_start = (
-                self.start.0 + (pos_in_viewport.0 * self.char_width),
-                self.start.1 + (pos_in_viewport.1 * self.char_height),
-            );
+    /// Pixels covered by a character `width` px wide at `pos` (px from the
+    /// left, row) relative to the viewport
+    fn cell_rect(&self, pos: (usize, usize), width: usize) -> Rect {
+        return Rect::new(
+            (
+                self.start.0 + pos.0,
+                self.start.1 + (pos.1 * self.char_height),
+            ),
+            (width, self.char_height),
+        );
+    }
 
-            let top_left = (
-                cell_start.0,
-                cell_start.1 + self.char_height - usize::from(CURSOR_WEIGHT),
-            );
+    /// Pixels the glyph of a character at `pos` might draw on. Proportional
+    /// glyphs can hang over their neighbours, by up to a cell either way.
+    fn ink_rect(&self, pos: (usize, usize)) -> Rect {
+        if self.monospace {
+            return self.cell_rect(pos, self.char_width);
+        }
+        let cell = self.cell_rect(pos, self.char_width * 2);
+        return Rect::new(
+            (cell.x.saturating_sub(self.char_width), cell.y),
+            cell.dimensions(),
+        );
+    }
+
+    /// Pixels covered by the character the cursor is on (or a space, past the
+    /// end of the line)
+    fn cursor_cell(&self, graphics: &Graphics) -> Rect {
+        let line = &self.content[self.cursor.1];
+        let under = line[self.cursor.0..].chars().next().unwrap_or(' ');
+        return self.cell_rect(
+            self.cursor_pos_in_viewport(graphics),
+            self.char_advance(graphics, under),
+        );
+    }
+
+    /// Draw the cursor along the bottom of its cell. Only draw the cursor in
+    /// edit mode.
+    fn maybe_draw_cursor(&self, graphics: &mut Graphics) {
+        if self.edit {
+            let cell = self.cursor_cell(graphics);
+            let top_left = (cell.x, cell.bottom() - usize::from(CURSOR_WEIGHT));
             graphics.draw_rect(
                 ColorType::Cursor,
                 top_left,
-                (self.char_width, usize::from(CURSOR_WEIGHT)),
+                (cell.width, usize::from(CURSOR_WEIGHT)),
                 None,
             );
         }
     }
 
+    fn border_color(focused: bool) -> ColorType {
+        if focused {
+            ColorType::BorderFocused
+        } else {
+            ColorType::BorderUnfocused
+        }
+    }
+
+    /// Redraw whichever bits of the one-pixel border fall inside `region`.
+    fn draw_border_within(&self, graphics: &mut Graphics, region: Rect, focused: bool) {
+        graphics.push_clip(region);
+        graphics.draw_outline(self.bounds(), 1, TextArea::border_color(focused));
+        graphics.pop_clip();
+    }
+
+    /// The cursor moved but the text didn't change. Unless the viewport had to
+    /// follow the cursor, only the cell the cursor left and the cell it landed
+    /// in need repainting.
+    fn redraw_cursor_move(&mut self, graphics: &mut Graphics, prev_cell: Rect) {
+        let viewport_start = self.viewport_start;
+        self.drag_viewport(graphics);
+        if self.viewport_start != viewport_start {
+            self.draw(graphics, true /* focused */);
+            return;
+        }
+        let cell = self.cursor_cell(graphics);
+        self.invalidate(graphics, prev_cell, true /* focused */);
+        self.invalidate(graphics, cell, true /* focused */);
+    }
+
     /// To handle text that is too many lines to fit on screen (or too many
     /// columns with `XOverflowBehavior::WRAP`) `TextArea` has a viewport that
     /// gets dragged along as the cursor moves to show the right part of the
     /// text.
-    fn drag_viewport(&mut self) {
+    fn drag_viewport(&mut self, graphics: &Graphics) {
         match self.x_overflow {
             XOverflowBehavior::Scroll => {
+                let column = self.cursor_column();
                 let move_if_left_of = self.viewport_start.0 + usize::from(SCROLLOFF);
-                let move_if_right_of =
-                    self.viewport_start.0 + self.dimensions_chars.0 - usize::from(SCROLLOFF);
-                if self.cursor.0 < move_if_left_of {
+                // keep `SCROLLOFF` cells of room to the right of the cursor
+                let room = self
+                    .line_width()
+                    .saturating_sub(usize::from(SCROLLOFF) * self.char_width);
+                if column < move_if_left_of {
                     self.viewport_start.0 -=
-                        cmp::min(self.viewport_start.0, move_if_left_of - self.cursor.0);
-                } else if self.cursor.0 > move_if_right_of {
-                    self.viewport_start.0 += self.cursor.0 - move_if_right_of;
+                        cmp::min(self.viewport_start.0, move_if_left_of - column);
+                } else {
+                    let before_cursor: Vec<char> = self.content[self.cursor.1][..self.cursor.0]
+                        .chars()
+                        .collect();
+                    while self.viewport_start.0 < column
+                        && before_cursor[self.viewport_start.0..]
+                            .iter()
+                            .map(|c| self.char_advance(graphics, *c))
+                            .sum::<usize>()
+                            > room
+                    {
+                        self.viewport_start.0 += 1;
+                    }
                 }
 
                 let move_if_above = self.viewport_start.1 + usize::from(SCROLLOFF);
@@ -187,7 +318,7 @@ impl TextArea {
                 let wrapped_lines_per_line: Vec<usize> = self
                     .content
                     .iter()
-                    .map(|line| self.wrap_line(line).len())
+                    .map(|line| self.wrap_line(graphics, line).len())
                     .collect();
                 let lines_for_active_row = wrapped_lines_per_line[self.cursor.1];
                 let move_if_above = self.viewport_start.1;
@@ -226,61 +357,102 @@ impl TextArea {
         }
     }
 
-    /// When moving up or down a shorter line, we have to cap our cursor's
-    /// horizontal permission at the length of the new line.
-    fn snap_cursor(&mut self) {
-        self.cursor.0 = cmp::min(self.cursor.0, self.content[self.cursor.1].len());
+    /// How many characters come before the cursor on its row
+    fn cursor_column(&self) -> usize {
+        return self.content[self.cursor.1][..self.cursor.0].chars().count();
     }
 
+    /// Put the cursor after `column` characters on its row. When moving up or
+    /// down a shorter line, we have to cap our cursor's horizontal permission
+    /// at the length of the new line.
+    fn snap_cursor(&mut self, column: usize) {
+        let line = &self.content[self.cursor.1];
+        self.cursor.0 = line
+            .char_indices()
+            .nth(column)
+            .map_or(line.len(), |(idx, _)| idx);
+    }
+
+    /// Byte index of the character before the cursor
+    fn prev_boundary(&self) -> usize {
+        let line = &self.content[self.cursor.1];
+        return line[..self.cursor.0]
+            .char_indices()
+            .last()
+            .map_or(0, |(idx, _)| idx);
+    }
+
+    /// Byte index of the character after the one the cursor is on
+    fn next_boundary(&self) -> usize {
+        let line = &self.content[self.cursor.1];
+        return line[self.cursor.0..]
+            .chars()
+            .next()
+            .map_or(self.cursor.0, |c| self.cursor.0 + c.len_utf8());
+    }
+
+    /// Draw `c` at `pos` (px from the left, row) relative to the viewport
     fn print_char(&self, graphics: &mut Graphics, c: char, pos: (usize, usize)) {
         let px = (
-            self.start.0 + (pos.0 * self.char_width),
+            self.start.0 + pos.0,
             self.start.1 + (pos.1 * self.char_height),
         );
 
-        graphics.write_char(c, px, FontSize::P, ColorType::Foreground);
+        if self.monospace {
+            graphics.write_char(c, px, self.font_size, ColorType::Foreground);
+        } else {
+            let mut buf = [0; 4];
+            graphics.write_text(
+                c.encode_utf8(&mut buf),
+                px,
+                self.font_size,
+                ColorType::Foreground,
+            );
+        }
     }
 
-    // split a string too long to fit into one line into several.
-    // returns accumulated indices e.g. [(0, 7), (7, 15), (15, 22)]
     /// Helper method to split a single line of text into multiple lines
-    /// according to the viewport dimensions. Returns Vec<(start, end)>, e.g.
+    /// according to the viewport width. Returns Vec<(start, end)>, e.g.
     ///   [(0, 7), (7, 15), (15, 23), (23, 25), (25, 33)]
     /// If a line contains a word that is too long to fit on one line, the word
     /// gets broken up onto multiple lines.
-    fn wrap_line(&self, s: &String) -> Vec<(usize, usize)> {
-        let words: Vec<&str> = s.split_inclusive(&['\t', ' '][..]).collect();
-        let cols = self.dimensions_chars.0;
-
+    fn wrap_line(&self, graphics: &Graphics, s: &String) -> Vec<(usize, usize)> {
+        let max_width = self.line_width();
         let mut wrapped: Vec<(usize, usize)> = vec![(0, 0)];
-        wrapped = words.iter().fold(wrapped, |mut acc, word| {
-            let last = acc.last_mut().expect("Shouldn't be empty");
-
-            if word.len() > cols {
-                let mut word_used = cols - (last.1 - last.0);
-                last.1 += word_used;
-                loop {
-                    if word_used == word.len() {
-                        break;
+        // px taken up on the last line so far
+        let mut used = 0;
+
+        for word in s.split_inclusive(&['\t', ' '][..]) {
+            let word_width = self.text_width(graphics, word);
+            let last = wrapped.last_mut().expect("Shouldn't be empty");
+            if used + word_width <= max_width {
+                last.1 += word.len();
+                used += word_width;
+            } else if word_width <= max_width {
+                let end = last.1;
+                wrapped.push((end, end + word.len()));
+                used = word_width;
+            } else {
+                // too long for any line: fill up this one and keep going on
+                // the next ones. every line gets at least one character
+                for c in word.chars() {
+                    let width = self.char_advance(graphics, c);
+                    let last = wrapped.last_mut().expect("Shouldn't be empty");
+                    if used + width > max_width && last.1 > last.0 {
+                        let end = last.1;
+                        wrapped.push((end, end));
+                        used = 0;
                     }
-                    let last = acc.last().expect("Shouldn't be empty").clone();
-                    let word_to_add = cmp::min(cols, word.len() - word_used);
-                    acc.push((last.1, last.1 + word_to_add));
-                    word_used += word_to_add;
+                    wrapped.last_mut().expect("Shouldn't be empty").1 += c.len_utf8();
+                    used += width;
                 }
-            } else if last.1 + word.len() - last.0 > cols {
-                let last_clone = last.clone();
-                acc.push((last_clone.1, last_clone.1 + word.len()));
-            } else {
-                last.1 += word.len();
             }
-            acc
-        });
+        }
         return wrapped;
     }
 
-    /// Helper to write text with line wrapping enabled
-    fn draw_with_x_wrapping(&mut self, graphics: &mut Graphics) {
+    /// Helper to lay out text with line wrapping enabled
+    fn layout_with_x_wrapping(&self, graphics: &Graphics) -> Vec<(char, (usize, usize))> {
         let rows = self.dimensions_chars.1;
 
         // only iterate through content within the viewport. we may not get
@@ -288,45 +460,70 @@ impl TextArea {
         let start_row = self.viewport_start.1;
         let max_end_row = cmp::min(self.content.len(), self.viewport_start.1 + rows);
 
+        let mut laid_out = Vec::new();
         let mut pos = (0, 0);
         for row in &self.content[start_row..max_end_row] {
-            let wrapped = self.wrap_line(&row);
+            let wrapped = self.wrap_line(graphics, &row);
             for line in wrapped {
                 if pos.1 >= self.dimensions_chars.1 {
-                    return;
+                    return laid_out;
                 }
                 pos.0 = 0;
                 for c in row[line.0..line.1].chars() {
-                    self.print_char(graphics, c, pos);
-                    pos.0 += 1;
+                    laid_out.push((c, pos));
+                    pos.0 += self.char_advance(graphics, c);
                 }
                 pos.1 += 1;
             }
         }
+        return laid_out;
     }
 
-    /// Helper to write text with scrolling enabled
-    fn draw_with_x_scrolling(&mut self, graphics: &mut Graphics) {
-        let cols = self.dimensions_chars.0;
+    /// Helper to lay out text with scrolling enabled
+    fn layout_with_x_scrolling(&self, graphics: &Graphics) -> Vec<(char, (usize, usize))> {
         let rows = self.dimensions_chars.1;
+        let max_width = self.line_width();
 
         let start_row = self.viewport_start.1;
         let end_row = cmp::min(self.content.len(), self.viewport_start.1 + rows);
 
+        let mut laid_out = Vec::new();
         let mut pos = (0, 0);
         for row in &self.content[start_row..end_row] {
-            let start_col = self.viewport_start.0;
-            let end_col = cmp::min(row.len(), self.viewport_start.0 + cols);
             pos.0 = 0;
-
-            if start_col < row.len() {
-                for c in row[start_col..end_col].chars() {
-                    self.print_char(graphics, c, pos);
-                    pos.0 += 1;
+            for c in row.chars().skip(self.viewport_start.0) {
+                if pos.0 >= max_width {
+                    break;
                 }
+                laid_out.push((c, pos));
+                pos.0 += self.char_advance(graphics, c);
             }
             pos.1 += 1;
         }
+        return laid_out;
+    }
+
+    /// Every character currently in the viewport along with where it goes, as
+    /// (px from the left, row) relative to the viewport.
+    fn visible_chars(&self, graphics: &Graphics) -> Vec<(char, (usize, usize))> {
+        return match &self.x_overflow {
+            XOverflowBehavior::Wrap => self.layout_with_x_wrapping(graphics),
+            XOverflowBehavior::Scroll => self.layout_with_x_scrolling(graphics),
+        };
+    }
+
+    /// Character dimensions depend on the theme which lives in `Graphics`.
+    fn set_char_dims(&mut self, graphics: &Graphics) {
+        if !self.char_dims_set {
+            let (char_width, char_height) = graphics.char_dimensions(self.font_size);
+            self.char_width = char_width;
+            self.char_height = char_height;
+
+            let cols = self.dimensions_px.0 / self.char_width;
+            let rows = self.dimensions_px.1 / self.char_height;
+            self.dimensions_chars = (cols, rows);
+            self.char_dims_set = true;
+        }
     }
 }
 
@@ -344,38 +541,52 @@ impl Widget for TextArea {
     }
 
     fn draw(&mut self, graphics: &mut Graphics, focused: bool) {
-        let border = if focused {
-            ColorType::BorderFocused
-        } else {
-            ColorType::BorderUnfocused
-        };
+        graphics.push_clip(self.bounds());
         graphics.draw_rect(
             ColorType::Background,
             self.start,
             self.dimensions_px,
-            Some(border),
+            Some(TextArea::border_color(focused)),
         );
 
-        if !self.char_dims_set {
-            self.char_width = graphics.theme.font_sizes.get(self.font_size) * font::FONT_WIDTH;
-            self.char_height = graphics.theme.font_sizes.get(self.font_size) * font::FONT_HEIGHT;
+        self.set_char_dims(graphics);
+        self.drag_viewport(graphics);
+        self.maybe_draw_cursor(graphics);
 
-            let cols = self.dimensions_px.0 / self.char_width;
-            let rows = self.dimensions_px.1 / self.char_height;
-            self.dimensions_chars = (cols, rows);
+        for (c, pos) in self.visible_chars(graphics) {
+            self.print_char(graphics, c, pos);
         }
+        graphics.pop_clip();
+    }
 
-        self.drag_viewport();
-        self.maybe_draw_cursor(graphics);
+    /// Only repaint the background, border, cursor and characters that overlap
+    /// `rect`. Moving the cursor repaints two characters instead of the whole
+    /// thing.
+    fn invalidate(&mut self, graphics: &mut Graphics, rect: Rect, focused: bool) {
+        let region = match rect.intersection(&self.bounds()) {
+            Some(value) => value,
+            None => return,
+        };
+        self.set_char_dims(graphics);
+        graphics.push_clip(region);
 
-        match &self.x_overflow {
-            XOverflowBehavior::Wrap => {
-                self.draw_with_x_wrapping(graphics);
-            }
-            XOverflowBehavior::Scroll => {
-                self.draw_with_x_scrolling(graphics);
+        graphics.draw_rect(
+            ColorType::Background,
+            region.top_left(),
+            region.dimensions(),
+            None,
+        );
+        self.draw_border_within(graphics, region, focused);
+
+        if self.cursor_cell(graphics).intersects(&region) {
+            self.maybe_draw_cursor(graphics);
+        }
+        for (c, pos) in self.visible_chars(graphics) {
+            if self.ink_rect(pos).intersects(&region) {
+                self.print_char(graphics, c, pos);
             }
         }
+        graphics.pop_clip();
     }
 
     fn handle_key(&mut self, k: Key, graphics: &mut Graphics) -> UIResult {
@@ -385,8 +596,9 @@ impl Widget for TextArea {
                     // BACKSPACE
                     // if we aren't the first char, just delete in place
                     if self.cursor.0 > 0 {
-                        self.content[self.cursor.1].remove(self.cursor.0 - 1);
-                        self.cursor.0 -= 1;
+                        let prev = self.prev_boundary();
+                        self.content[self.cursor.1].remove(prev);
+                        self.cursor.0 = prev;
                         self.draw(graphics, true /* focused */);
                     } else {
                         // we are the first char. are we the first row? if not, merge rows
@@ -417,46 +629,51 @@ impl Widget for TextArea {
                     self.cursor.0 = 0;
                     self.draw(graphics, true /* focused */);
                 }
-                _ => {
-                    self.content[self.cursor.1].insert(self.cursor.0, char::from(value));
-                    self.cursor.0 += 1;
+                c => {
+                    self.content[self.cursor.1].insert(self.cursor.0, c);
+                    self.cursor.0 += c.len_utf8();
                     self.draw(graphics, true /* focused */);
                 }
             },
             Key::Special(value) => match value {
                 ScanCode::LEFT => {
                     if self.cursor.0 > 0 {
-                        self.cursor.0 -= 1;
-                        self.draw(graphics, true /* focused */);
+                        let prev_cell = self.cursor_cell(graphics);
+                        self.cursor.0 = self.prev_boundary();
+                        self.redraw_cursor_move(graphics, prev_cell);
                     }
                 }
                 ScanCode::RIGHT => {
                     // allow cursor to go one past end of row for backspacing
                     if self.cursor.0 < self.content[self.cursor.1].len() {
-                        self.cursor.0 += 1;
-                        self.snap_cursor();
-                        self.draw(graphics, true /* focused */);
+                        let prev_cell = self.cursor_cell(graphics);
+                        self.cursor.0 = self.next_boundary();
+                        self.redraw_cursor_move(graphics, prev_cell);
                     }
                 }
                 ScanCode::UP => {
                     // if not already at the top
                     if self.cursor.1 > 0 {
+                        let prev_cell = self.cursor_cell(graphics);
+                        let column = self.cursor_column();
                         self.cursor.1 -= 1;
-                        self.snap_cursor();
-                        self.draw(graphics, true /* focused */);
+                        self.snap_cursor(column);
+                        self.redraw_cursor_move(graphics, prev_cell);
                     }
                 }
                 ScanCode::DOWN => {
                     // if not already at the bottom
                     if self.cursor.1 < self.content.len() - 1 {
+                        let prev_cell = self.cursor_cell(graphics);
+                        let column = self.cursor_column();
                         self.cursor.1 += 1;
-                        self.snap_cursor();
-                        self.draw(graphics, true /* focused */);
+                        self.snap_cursor(column);
+                        self.redraw_cursor_move(graphics, prev_cell);
                     }
                 }
                 ScanCode::DELETE => {
                     // if we aren't the last char, just delete in place
-                    if self.cursor.0 < cmp::max(self.content[self.cursor.1].len(), 1) - 1 {
+                    if self.next_boundary() < self.content[self.cursor.1].len() {
                         self.content[self.cursor.1].remove(self.cursor.0);
                         self.draw(graphics, true /* focused */);
                     } else {
@@ -482,4 +699,186 @@ impl Widget for TextArea {
     fn dimensions(&mut self) -> (usize, usize) {
         return self.dimensions_px;
     }
+
+    fn bounds(&self) -> Rect {
+        return Rect::new(self.start, self.dimensions_px);
+    }
+
+    /// Recompute the character grid for the new size (and whatever the font
+    /// size is now) and drag the viewport back to the cursor.
+    fn resize(&mut self, graphics: &Graphics, rect: Rect) {
+        self.start = rect.top_left();
+        self.dimensions_px = rect.dimensions();
+        self.char_dims_set = false;
+        self.set_char_dims(graphics);
+        self.drag_viewport(graphics);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::{ColorScheme, FontSizes, Theme};
+    use crate::ui::core::font;
+    use crate::ui::core::render_target::MemoryTarget;
+    use no_std_compat::convert::TryFrom;
+    use test::Bencher;
+    use uefi::Char16;
+
+    fn graphics(resolution: (usize, usize)) -> Graphics<'static> {
+        let theme = Theme {
+            font_sizes: FontSizes::new(2, 2, 2),
+            color_scheme: ColorScheme::new(
+                [200, 200, 200],
+                [20, 20, 20],
+                [0, 255, 0],
+                [90, 90, 90],
+                [0, 0, 255],
+            ),
+            font: String::from(font::BUILTIN),
+        };
+        return Graphics::new(Box::new(MemoryTarget::new(resolution)), theme);
+    }
+
+    /// A screenful of text redrawn from scratch, background and all, then
+    /// pushed to the screen. `cargo bench` to see how long it takes.
+    #[bench]
+    fn full_screen_redraw(b: &mut Bencher) {
+        let resolution = (1024, 768);
+        let mut graphics = graphics(resolution);
+        let line: String = (0x20u8..0x7f).map(char::from).collect();
+        let mut text_area = TextArea::new(
+            String::from("bench"),
+            vec![],
+            vec![line; 80].join("\n"),
+            true,
+            (0, 0),
+            resolution,
+            TextAreaOptions::default(),
+        );
+        b.iter(|| {
+            text_area.draw(&mut graphics, true);
+            graphics.present();
+        });
+    }
+
+    #[test]
+    fn resize_redoes_the_grid() {
+        let mut graphics = graphics((400, 300));
+        let mut text_area = TextArea::new(
+            String::from("text"),
+            vec![],
+            String::new(),
+            true,
+            (0, 0),
+            (90, 44),
+            TextAreaOptions::default(),
+        );
+        let rect = Rect::new((10, 10), (180, 110));
+        text_area.resize(&graphics, rect);
+        assert_eq!(text_area.bounds(), rect);
+        let cell = (2 * font::FONT_WIDTH, 2 * font::FONT_HEIGHT);
+        assert_eq!(text_area.dimensions_chars, (180 / cell.0, 110 / cell.1));
+
+        graphics.theme.font_sizes = FontSizes::new(1, 1, 1);
+        text_area.resize(&graphics, rect);
+        let cell = (font::FONT_WIDTH, font::FONT_HEIGHT);
+        assert_eq!(text_area.dimensions_chars, (180 / cell.0, 110 / cell.1));
+    }
+
+    fn laid_out(graphics: &Graphics, content: &str, monospace: bool) -> TextArea {
+        let options = TextAreaOptions {
+            monospace: monospace,
+            ..TextAreaOptions::default()
+        };
+        let mut text_area = TextArea::new(
+            String::from("text"),
+            vec![],
+            String::from(content),
+            true,
+            (0, 0),
+            (200, 100),
+            options,
+        );
+        text_area.resize(graphics, Rect::new((0, 0), (200, 100)));
+        return text_area;
+    }
+
+    #[test]
+    fn proportional_cursor_follows_the_text() {
+        let graphics = graphics((400, 300));
+        let i = graphics.char_advance('i', FontSize::P);
+        let m = graphics.char_advance('M', FontSize::P);
+
+        let mut text_area = laid_out(&graphics, "iM", false);
+        assert!(i < m && m <= text_area.char_width);
+        text_area.cursor = (1, 0);
+        assert_eq!(text_area.cursor_pos_in_viewport(&graphics), (i, 0));
+        // as wide as the character it's under
+        let height = text_area.char_height;
+        assert_eq!(
+            text_area.cursor_cell(&graphics),
+            Rect::new((i, 0), (m, height))
+        );
+
+        let mut text_area = laid_out(&graphics, "iM", true);
+        text_area.cursor = (1, 0);
+        let width = text_area.char_width;
+        assert_eq!(text_area.cursor_pos_in_viewport(&graphics), (width, 0));
+        assert_eq!(
+            text_area.cursor_cell(&graphics),
+            Rect::new((width, 0), (width, height))
+        );
+    }
+
+    #[test]
+    fn narrow_text_fits_more_on_a_line() {
+        let graphics = graphics((400, 300));
+        let line = "i".repeat(30);
+        let i = graphics.char_advance('i', FontSize::P);
+        let proportional = laid_out(&graphics, &line, false);
+        assert_eq!(proportional.wrap_line(&graphics, &line)[0], (0, 200 / i));
+        // 200 px of 18 px cells is 11 a line
+        let monospace = laid_out(&graphics, &line, true);
+        assert_eq!(
+            monospace.wrap_line(&graphics, &line),
+            [(0, 11), (11, 22), (22, 30)]
+        );
+    }
+
+    fn typed(c: char) -> Key {
+        return Key::Printable(Char16::try_from(c).unwrap());
+    }
+
+    #[test]
+    fn editing_stays_on_char_boundaries() {
+        let mut graphics = graphics((400, 300));
+        let mut text_area = laid_out(&graphics, "é─b\nxy", true);
+        let right = Key::Special(ScanCode::RIGHT);
+        text_area.handle_key(right, &mut graphics);
+        assert_eq!(text_area.cursor, (2, 0));
+        text_area.handle_key(right, &mut graphics);
+        assert_eq!(text_area.cursor, (5, 0));
+
+        text_area.handle_key(typed('ü'), &mut graphics);
+        assert_eq!(text_area.content[0], "é─üb");
+        assert_eq!(text_area.cursor, (7, 0));
+        text_area.handle_key(typed('\x08'), &mut graphics);
+        text_area.handle_key(typed('\x08'), &mut graphics);
+        assert_eq!(text_area.content[0], "éb");
+        assert_eq!(text_area.cursor, (2, 0));
+
+        // up and down keep the column in chars, not bytes
+        text_area.handle_key(Key::Special(ScanCode::DOWN), &mut graphics);
+        assert_eq!(text_area.cursor, (1, 1));
+        text_area.handle_key(right, &mut graphics);
+        text_area.handle_key(Key::Special(ScanCode::UP), &mut graphics);
+        assert_eq!(text_area.cursor, (3, 0));
+
+        text_area.handle_key(Key::Special(ScanCode::LEFT), &mut graphics);
+        text_area.handle_key(Key::Special(ScanCode::LEFT), &mut graphics);
+        assert_eq!(text_area.cursor, (0, 0));
+        text_area.handle_key(Key::Special(ScanCode::DELETE), &mut graphics);
+        assert_eq!(text_area.content[0], "b");
+    }
 }